@@ -0,0 +1,156 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::answers::Case;
+use common::{Error, Format, Report};
+
+use crate::days;
+use crate::perf;
+
+/// Runs `parts` of every day on its puzzle input, each day on a thread of its own. Rows are printed
+/// as the days finish, so the slow days hold up nobody. Every part that ran goes to the performance
+/// log. Returns whether no part failed.
+pub fn run_all(parts: &[u8], format: Format, cases: &[Case], log: &perf::Log) -> bool {
+    // Bars of twelve days at once would only garble each other
+    common::progress::set_quiet(true);
+
+    let start = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let mut summary = Summary::default();
+    let mut solved = Vec::new();
+
+    thread::scope(|scope| {
+        for &day in days::DAYS {
+            let sender = sender.clone();
+            scope.spawn(move || {
+                let filename = days::default_input(day);
+                let result = panic::catch_unwind(AssertUnwindSafe(|| days::solve(day, parts, &filename)));
+                let _ = sender.send((day, result));
+            });
+        }
+        drop(sender);
+
+        if format == Format::Text {
+            println!("{:<4} {:<4} {:>20} {:>20} {:>12}  Result", "Day", "Part", "Answer", "Expected", "Time");
+        }
+        for (day, result) in receiver {
+            match result {
+                Ok(Ok(reports)) => {
+                    for report in &reports {
+                        let result = summary.count(check(report, cases));
+                        match format {
+                            Format::Text => print_row(report, cases, result),
+                            Format::Json => println!("{}", report.to_json()),
+                        }
+                    }
+                    solved.extend(reports);
+                }
+                Ok(Err(error)) => summary.fail(day, parts, format, &format!("error: {}", error)),
+                Err(_) => summary.fail(day, parts, format, "panicked"),
+            }
+        }
+    });
+    if format == Format::Text {
+        println!();
+        println!("{} passed, {} failed, {} without a known answer in {:.2?}",
+                 summary.passed, summary.failed, summary.unknown, start.elapsed());
+    }
+    log.record_or_warn(&solved);
+    summary.failed == 0
+}
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    unknown: usize,
+}
+
+impl Summary {
+    fn count(&mut self, result: Option<bool>) -> Option<bool> {
+        match result {
+            Some(true) => self.passed += 1,
+            Some(false) => self.failed += 1,
+            None => self.unknown += 1,
+        }
+        result
+    }
+
+    /// Every part of a day that did not get as far as an answer fails.
+    fn fail(&mut self, day: u8, parts: &[u8], format: Format, reason: &str) {
+        self.failed += parts.len();
+        match format {
+            Format::Text => {
+                for part in parts {
+                    println!("{:<4} {:<4} {:>20} {:>20} {:>12}  FAIL", format!("{:02}", day), part, "-", "", "");
+                }
+                println!("     + {}", reason);
+            }
+            Format::Json => eprintln!("day {:02}: {}", day, reason),
+        }
+    }
+}
+
+/// Whether the answer is the known one, `None` where no answer is known.
+fn check(report: &Report, cases: &[Case]) -> Option<bool> {
+    expected(report, cases).map(|case| case.expected == report.answer)
+}
+
+fn expected<'a>(report: &Report, cases: &'a [Case]) -> Option<&'a Case> {
+    cases.iter().find(|case| case.day == report.day && case.part == report.part && case.input == "input.dat")
+}
+
+fn print_row(report: &Report, cases: &[Case], result: Option<bool>) {
+    let expected = expected(report, cases).map_or(String::new(), |case| case.expected.to_string());
+    let time: Duration = report.parse_time + report.solve_time;
+    println!("{:<4} {:<4} {:>20} {:>20} {:>12}  {}",
+             format!("{:02}", report.day),
+             report.part,
+             report.answer.to_string(),
+             expected,
+             format!("{:.1?}", time),
+             match result {
+                 Some(true) => "pass",
+                 Some(false) => "FAIL",
+                 None => "",
+             });
+}
+
+/// The answers file is optional for a run, without it no answer is checked.
+pub fn load_cases(filename: &str) -> Result<Vec<Case>, Error> {
+    match common::answers::load(filename) {
+        Err(Error::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Answer;
+
+    use crate::stub::report;
+
+    use super::*;
+
+    #[test]
+    fn checks_against_the_puzzle_input_answers() {
+        let cases = vec![
+            Case { day: 1, input: "test.dat".to_string(), part: 1, expected: Answer::Number(3) },
+            Case { day: 1, input: "input.dat".to_string(), part: 1, expected: Answer::Number(980) },
+        ];
+        let mut summary = Summary::default();
+
+        assert_eq!(summary.count(check(&report(1, 1, &days::default_input(1), Answer::Number(980)), &cases)), Some(true));
+        assert_eq!(summary.count(check(&report(1, 1, &days::default_input(1), Answer::Number(3)), &cases)), Some(false));
+        assert_eq!(summary.count(check(&report(1, 2, &days::default_input(1), Answer::Number(3)), &cases)), None);
+        assert_eq!((summary.passed, summary.failed, summary.unknown), (1, 1, 1));
+    }
+
+    #[test]
+    fn a_missing_answers_file_checks_nothing() {
+        assert!(load_cases("no-such-answers.toml").unwrap().is_empty());
+        assert!(!load_cases("../answers.toml").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use std::{env, fs, io, thread};
+
+use common::Error;
+
+use crate::config::Config;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2025;
+
+/// Seconds between two requests unless configured otherwise.
+const DEFAULT_INTERVAL: u64 = 60;
+
+/// Talks to the Advent of Code server on behalf of one account, never faster than the interval
+/// allows. The time of the last request is kept in a file, so the limit holds across runs.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    interval: Duration,
+    last_request: PathBuf,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, interval: Duration, last_request: PathBuf) -> Client {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            interval,
+            last_request,
+        }
+    }
+
+    /// Uses `AOC_SESSION` and `AOC_BASE_URL` from the environment, falling back to the config.
+    pub fn from_config(config: &Config) -> Result<Client, Error> {
+        let session = env::var("AOC_SESSION").ok()
+            .or_else(|| config.session.clone())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound,
+                                          "no session token, set AOC_SESSION or session in aoc.toml"))?;
+        let base_url = env::var("AOC_BASE_URL").ok()
+            .or_else(|| config.base_url.clone())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let interval = Duration::from_secs(config.request_interval.unwrap_or(DEFAULT_INTERVAL));
+
+        Ok(Client::new(&base_url, &session, interval, PathBuf::from(".aoc/last-request")))
+    }
+
+    /// Fetches `path`, relative to the base URL, as text.
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        self.throttle()?;
+        let url = format!("{}{}", self.base_url, path);
+        let response = self.agent.get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|error| request_error(&url, error))?;
+        read(&url, response)
+    }
+
+    /// Posts `form` to `path`. Being rate limited is not an error here, the caller decides
+    /// whether to wait and try again.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<Reply, Error> {
+        self.throttle()?;
+        let url = format!("{}{}", self.base_url, path);
+        let result = self.agent.post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form);
+        match result {
+            Ok(response) => read(&url, response).map(Reply::Page),
+            Err(ureq::Error::Status(429, response)) => {
+                Ok(Reply::RateLimited(response.header("Retry-After").and_then(|seconds| seconds.parse().ok())))
+            }
+            Err(error) => Err(request_error(&url, error)),
+        }
+    }
+
+    /// Waits until the interval since the last request has passed, then records this request.
+    fn throttle(&self) -> Result<(), Error> {
+        let elapsed = fs::metadata(&self.last_request)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+        if let Some(remaining) = elapsed.and_then(|elapsed| self.interval.checked_sub(elapsed)) {
+            eprintln!("Waiting {}s before the next request", remaining.as_secs() + 1);
+            thread::sleep(remaining);
+        }
+
+        if let Some(directory) = self.last_request.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        fs::write(&self.last_request, b"")
+            .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", self.last_request.display(), error)))?;
+        Ok(())
+    }
+}
+
+/// What the server answered to a post.
+pub enum Reply {
+    Page(String),
+    /// Too many requests, with the seconds to wait if the server said so.
+    RateLimited(Option<u64>),
+}
+
+fn read(url: &str, response: ureq::Response) -> Result<String, Error> {
+    response.into_string().map_err(|error| io::Error::new(error.kind(), format!("{}: {}", url, error)).into())
+}
+
+fn request_error(url: &str, error: ureq::Error) -> Error {
+    let message = match error {
+        ureq::Error::Status(429, response) => match response.header("Retry-After") {
+            Some(seconds) => format!("{}: rate limited, retry after {} seconds", url, seconds),
+            None => format!("{}: rate limited", url),
+        },
+        ureq::Error::Status(status, response) => format!("{}: {} {}", url, status, response.status_text()),
+        // The transport error names the URL itself
+        ureq::Error::Transport(transport) => transport.to_string(),
+    };
+    io::Error::other(message).into()
+}
@@ -0,0 +1,29 @@
+use std::{fs, io};
+
+use common::Error;
+use serde::Deserialize;
+
+/// Settings read from `aoc.toml` in the working directory. Every setting is optional and the
+/// file itself may be missing.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Session cookie of the Advent of Code account, `AOC_SESSION` takes precedence.
+    pub session: Option<String>,
+    /// Where to download from, `AOC_BASE_URL` takes precedence.
+    pub base_url: Option<String>,
+    /// Minimum number of seconds between two requests to the server.
+    pub request_interval: Option<u64>,
+}
+
+pub const FILENAME: &str = "aoc.toml";
+
+pub fn load(filename: &str) -> Result<Config, Error> {
+    let text = match fs::read_to_string(filename) {
+        Ok(text) => text,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+        Err(error) => return Err(io::Error::new(error.kind(), format!("{}: {}", filename, error)).into()),
+    };
+    toml::from_str(&text)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", filename, error)).into())
+}
@@ -0,0 +1,323 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+use std::{env, io, thread};
+
+use common::answers::Case;
+use common::{Answer, Error};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::days;
+use crate::watch::{self, PartReport};
+
+const INPUTS: [&str; 2] = ["test.dat", "input.dat"];
+
+/// The `-v` flags passed to a run and how the trace pane names them.
+const VERBOSITY: [(&[&str], &str); 3] = [(&[], "off"), (&["-v"], "debug"), (&["-vv"], "trace")];
+
+/// Lines of trace output kept from the last run, the rest scrolled out long before.
+const TRACE_LINES: usize = 1000;
+
+enum Status {
+    Running,
+    Answered(PartReport),
+    Failed,
+}
+
+/// One part of one day, a row of the table.
+struct Part {
+    day: u8,
+    part: u8,
+    /// The last run on each of the inputs.
+    runs: [Option<Status>; 2],
+}
+
+enum Message {
+    Trace(String),
+    Done { row: usize, input: usize, status: Status },
+}
+
+struct App {
+    parts: Vec<Part>,
+    cases: Vec<Case>,
+    table: TableState,
+    /// Index into `INPUTS`.
+    input: usize,
+    /// Index into `VERBOSITY`.
+    verbosity: usize,
+    trace: VecDeque<String>,
+    running: bool,
+    sender: Sender<Message>,
+    receiver: Receiver<Message>,
+    quit: bool,
+}
+
+/// Shows every day with its answers until `q` is pressed.
+pub fn dashboard(cases: Vec<Case>) -> Result<(), Error> {
+    let mut terminal = ratatui::init();
+    let result = App::new(cases).run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+impl App {
+    fn new(cases: Vec<Case>) -> App {
+        let (sender, receiver) = mpsc::channel();
+        App {
+            parts: days::DAYS.iter()
+                             .flat_map(|&day| [1, 2].map(|part| Part { day, part, runs: [None, None] }))
+                             .collect(),
+            cases,
+            table: TableState::default().with_selected(0),
+            input: 0,
+            verbosity: 1,
+            trace: VecDeque::new(),
+            running: false,
+            sender,
+            receiver,
+            quit: false,
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<(), Error> {
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame))?;
+            if event::poll(Duration::from_millis(100))? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press {
+                        self.key(key.code);
+                    }
+                }
+            }
+            while let Ok(message) = self.receiver.try_recv() {
+                self.receive(message);
+            }
+        }
+        Ok(())
+    }
+
+    fn key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Up | KeyCode::Char('k') => self.table.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => {
+                // `select_next` only stops at the last row when drawing
+                let selected = self.table.selected().map_or(0, |row| (row + 1).min(self.parts.len() - 1));
+                self.table.select(Some(selected));
+            }
+            KeyCode::Char('i') | KeyCode::Tab => self.input = (self.input + 1) % INPUTS.len(),
+            KeyCode::Char('v') => self.verbosity = (self.verbosity + 1) % VERBOSITY.len(),
+            KeyCode::Enter | KeyCode::Char('r') => self.start(),
+            _ => {}
+        }
+    }
+
+    /// Runs the selected part in a child process, so its trace can be caught and the table stays
+    /// responsive. Only one part runs at a time.
+    fn start(&mut self) {
+        let Some(row) = self.table.selected() else { return };
+        if self.running {
+            return;
+        }
+        self.running = true;
+        self.trace.clear();
+        self.parts[row].runs[self.input] = Some(Status::Running);
+
+        let Part { day, part, .. } = self.parts[row];
+        let input = self.input;
+        let verbosity = VERBOSITY[self.verbosity].0;
+        let sender = self.sender.clone();
+        thread::spawn(move || {
+            let status = solve(day, part, input, verbosity, &sender).unwrap_or_else(|error| {
+                let _ = sender.send(Message::Trace(error.to_string()));
+                Status::Failed
+            });
+            let _ = sender.send(Message::Done { row, input, status });
+        });
+    }
+
+    fn receive(&mut self, message: Message) {
+        match message {
+            Message::Trace(line) => {
+                if self.trace.len() == TRACE_LINES {
+                    self.trace.pop_front();
+                }
+                self.trace.push_back(line);
+            }
+            Message::Done { row, input, status } => {
+                self.parts[row].runs[input] = Some(status);
+                self.running = false;
+            }
+        }
+    }
+
+    fn expected(&self, part: &Part, input: usize) -> Option<&Answer> {
+        self.cases.iter()
+                  .find(|case| case.day == part.day && case.part == part.part && case.input == INPUTS[input])
+                  .map(|case| &case.expected)
+    }
+
+    /// The result column, `pass` and `FAIL` only where the answer is known.
+    fn result(&self, part: &Part, input: usize) -> &'static str {
+        match (&part.runs[input], self.expected(part, input)) {
+            (None, _) => "",
+            (Some(Status::Running), _) => "running",
+            (Some(Status::Failed), _) => "error",
+            (Some(Status::Answered(report)), Some(expected)) if expected.to_string() == report.answer => "pass",
+            (Some(Status::Answered(_)), Some(_)) => "FAIL",
+            (Some(Status::Answered(_)), None) => "done",
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, footer] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [left, right] = Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(main);
+
+        let rows: Vec<Row> = self.parts.iter().map(|part| {
+            let result = self.result(part, self.input);
+            let (answer, time) = match &part.runs[self.input] {
+                Some(Status::Answered(report)) => (report.answer.clone(), format!("{:.3} ms", report.solve_ms)),
+                _ => (String::new(), String::new()),
+            };
+            let expected = self.expected(part, self.input).map_or(String::new(), Answer::to_string);
+            let color = match result {
+                "pass" => Color::Green,
+                "FAIL" | "error" => Color::Red,
+                "running" => Color::Yellow,
+                _ => Color::Reset,
+            };
+            Row::new([format!("{:02}", part.day), part.part.to_string(), result.to_string(), answer, expected, time])
+                .style(Style::new().fg(color))
+        }).collect();
+
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Length(4),
+            Constraint::Length(7),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(12),
+        ];
+        let table = Table::new(rows, widths)
+            .header(Row::new(["Day", "Part", "Result", "Answer", "Expected", "Time"]).style(Style::new().add_modifier(Modifier::BOLD)))
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .block(Block::bordered().title(format!(" Days on {} ", INPUTS[self.input])));
+        frame.render_stateful_widget(table, left, &mut self.table);
+
+        // Only the lines that fit, the newest at the bottom
+        let height = right.height.saturating_sub(2) as usize;
+        let trace: Vec<Line> = self.trace.iter()
+                                         .skip(self.trace.len().saturating_sub(height))
+                                         .map(|line| Line::raw(line.as_str()))
+                                         .collect();
+        let title = format!(" Trace ({}) ", VERBOSITY[self.verbosity].1);
+        frame.render_widget(Paragraph::new(trace).block(Block::bordered().title(title)), right);
+
+        let help = "↑/↓ select  enter run  i switch input  v trace level  q quit";
+        frame.render_widget(Paragraph::new(help).style(Style::new().fg(Color::DarkGray)), footer);
+    }
+}
+
+/// Runs `aoc run` on one part, sending what it traces line by line.
+fn solve(day: u8, part: u8, input: usize, verbosity: &[&str], sender: &Sender<Message>) -> Result<Status, Error> {
+    let mut child = Command::new(env::current_exe()?)
+        .args(["run", &day.to_string(), "--part", &part.to_string(), "--format", "json", "--quiet", "--input"])
+        .arg(format!("day-{:02}/input/{}", day, INPUTS[input]))
+        .args(verbosity)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    if let Some(stderr) = child.stderr.take() {
+        for line in BufReader::new(stderr).lines() {
+            let _ = sender.send(Message::Trace(line?));
+        }
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Ok(Status::Failed);
+    }
+
+    let mut reports = watch::read_reports(&String::from_utf8_lossy(&output.stdout))
+        .map_err(|message| io::Error::new(io::ErrorKind::InvalidData, message))?;
+    match reports.pop() {
+        Some(report) => Ok(Status::Answered(report)),
+        None => Ok(Status::Failed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cases() -> Vec<Case> {
+        vec![
+            Case { day: 1, input: "test.dat".to_string(), part: 1, expected: Answer::Number(3) },
+            Case { day: 1, input: "test.dat".to_string(), part: 2, expected: Answer::Number(6) },
+        ]
+    }
+
+    fn answered(answer: &str) -> Status {
+        Status::Answered(PartReport { part: 1, input: "test.dat".to_string(), answer: answer.to_string(), parse_ms: 0.0, solve_ms: 0.0 })
+    }
+
+    #[test]
+    fn lists_both_parts_of_every_day() {
+        let app = App::new(cases());
+        assert_eq!(app.parts.len(), 2 * days::DAYS.len());
+        assert_eq!((app.parts[3].day, app.parts[3].part), (2, 2));
+    }
+
+    #[test]
+    fn keys_move_and_switch() {
+        let mut app = App::new(cases());
+        app.key(KeyCode::Up);
+        assert_eq!(app.table.selected(), Some(0));
+        for _ in 0..100 {
+            app.key(KeyCode::Down);
+        }
+        assert_eq!(app.table.selected(), Some(app.parts.len() - 1));
+
+        app.key(KeyCode::Char('i'));
+        assert_eq!(INPUTS[app.input], "input.dat");
+        app.key(KeyCode::Tab);
+        assert_eq!(INPUTS[app.input], "test.dat");
+
+        app.key(KeyCode::Char('v'));
+        assert_eq!(VERBOSITY[app.verbosity].1, "trace");
+        app.key(KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn results_compare_with_the_expected_answers() {
+        let mut app = App::new(cases());
+        assert_eq!(app.result(&app.parts[0], 0), "");
+
+        app.receive(Message::Done { row: 0, input: 0, status: answered("3") });
+        app.receive(Message::Done { row: 1, input: 0, status: answered("7") });
+        app.receive(Message::Done { row: 0, input: 1, status: answered("980") });
+        app.receive(Message::Done { row: 2, input: 0, status: Status::Failed });
+        assert_eq!(app.result(&app.parts[0], 0), "pass");
+        assert_eq!(app.result(&app.parts[1], 0), "FAIL");
+        assert_eq!(app.result(&app.parts[0], 1), "done");
+        assert_eq!(app.result(&app.parts[2], 0), "error");
+    }
+
+    #[test]
+    fn keeps_the_end_of_the_trace() {
+        let mut app = App::new(cases());
+        for index in 0..TRACE_LINES + 5 {
+            app.receive(Message::Trace(index.to_string()));
+        }
+        assert_eq!(app.trace.len(), TRACE_LINES);
+        assert_eq!(app.trace.front().map(String::as_str), Some("5"));
+    }
+}
@@ -0,0 +1,31 @@
+use common::{Error, ParseError, Report, Solution};
+use solutions::with_day;
+
+pub use solutions::DAYS;
+
+/// Parses a day argument, accepting the days that have a solution.
+pub fn registered(text: &str) -> Result<u8, String> {
+    text.parse().ok().filter(|day| DAYS.contains(day)).ok_or_else(|| {
+        let days: Vec<String> = DAYS.iter().map(u8::to_string).collect();
+        format!("expected one of the days with a solution: {}", days.join(", "))
+    })
+}
+
+pub fn default_input(day: u8) -> String {
+    format!("day-{:02}/input/input.dat", day)
+}
+
+/// Answers `parts` of `day` for the puzzle input in `filename`.
+pub fn solve(day: u8, parts: &[u8], filename: &str) -> Result<Vec<Report>, Error> {
+    with_day!(day, S => common::run::<S>(parts, filename))
+}
+
+/// Answers `parts` of `day` for a puzzle input that is already in memory, timing them like `solve`.
+pub fn run_text(day: u8, parts: &[u8], name: &str, text: &str) -> Result<Vec<Report>, ParseError> {
+    with_day!(day, S => common::run_text::<S>(parts, name, text))
+}
+
+/// Checks that `text` is a valid input for `day`.
+pub fn parse(day: u8, text: &str) -> Result<(), ParseError> {
+    with_day!(day, S => S::parse(text).map(|_| ()))
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+use std::{fs, io};
+
+use common::Error;
+
+use crate::client::{Client, YEAR};
+
+/// Downloads the input of `day` to `filename`, unless it is already there. Returns whether it was
+/// downloaded. `connect` only runs for a download, a cached input needs no session.
+pub fn fetch(connect: impl FnOnce() -> Result<Client, Error>, day: u8, filename: &str) -> Result<bool, Error> {
+    if Path::new(filename).exists() {
+        return Ok(false);
+    }
+
+    let text = connect()?.get(&format!("/{}/day/{}/input", YEAR, day))?;
+
+    // Write to a temporary file first, an interrupted download must not end up in the cache
+    let partial = format!("{}.partial", filename);
+    if let Some(directory) = Path::new(filename).parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::write(&partial, text)
+        .and_then(|_| fs::rename(&partial, filename))
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", filename, error)))?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::stub::{self, rate_limited, reply, scratch};
+
+    use super::*;
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() {
+        let (url, requests) = stub::serve(vec![reply(200, "L68\nR48\n")]);
+        let directory = scratch("fetch-cache");
+        let client = || Ok(Client::new(&url, "secret", Duration::ZERO, directory.join("last-request")));
+        let filename = directory.join("day-01/input/input.dat");
+        let filename = filename.to_str().unwrap();
+
+        assert!(fetch(client, 1, filename).unwrap());
+        assert!(!fetch(|| panic!("a cached input needs no client"), 1, filename).unwrap());
+
+        assert_eq!(fs::read_to_string(filename).unwrap(), "L68\nR48\n");
+        let request = requests.recv().unwrap();
+        assert_eq!((request.url.as_str(), request.cookie.as_str()), ("/2025/day/1/input", "session=secret"));
+        assert!(requests.recv_timeout(Duration::from_millis(100)).is_err());
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let (url, _requests) = stub::serve(vec![reply(404, "Not found"), rate_limited(30)]);
+        let directory = scratch("fetch-failed");
+        let client = || Ok(Client::new(&url, "secret", Duration::ZERO, directory.join("last-request")));
+        let filename = directory.join("input.dat");
+        let filename = filename.to_str().unwrap();
+
+        let error = fetch(client, 13, filename).unwrap_err();
+        assert!(error.to_string().ends_with("/2025/day/13/input: 404 Not Found"), "{}", error);
+        let error = fetch(client, 13, filename).unwrap_err();
+        assert!(error.to_string().ends_with("rate limited, retry after 30 seconds"), "{}", error);
+        assert!(!Path::new(filename).exists());
+    }
+
+    #[test]
+    fn requests_keep_their_distance() {
+        let (url, _requests) = stub::serve(vec![reply(200, "1"), reply(200, "2")]);
+        let directory = scratch("fetch-throttle");
+        let client = Client::new(&url, "secret", Duration::from_millis(300), directory.join("last-request"));
+
+        let start = Instant::now();
+        client.get("/first").unwrap();
+        client.get("/second").unwrap();
+        // The time of the last request is a file's mtime, which can be a few milliseconds coarse
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+}
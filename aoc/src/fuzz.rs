@@ -0,0 +1,211 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use common::ParseError;
+use rand::seq::IndexedRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{days, gen};
+
+/// Longest a parser may take on one input before it counts as hanging.
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Characters the mutations insert: the syntax of every day, whitespace, signs and a few that no
+/// day expects.
+const ALPHABET: &[char] = &[
+    '0', '1', '5', '9', '-', '+', '*', ',', ':', ' ', '\n', '\t', '\r', 'x', 'L', 'R', '@', '.', '#',
+    '^', 'S', '[', ']', '(', ')', '{', '}', 'a', 'z', 'é', '\0',
+];
+
+/// Numbers that are too big, negative or zero where a day may not expect them.
+const NUMBERS: &[&str] = &["0", "-1", "-", "4294967296", "18446744073709551616", "99999999999999999999999999999999999999999"];
+
+/// How parsing one input went.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Parsed,
+    Rejected,
+    Panicked(String),
+    Hung,
+}
+
+/// What a fuzzing run found.
+pub struct Summary {
+    pub parsed: usize,
+    pub rejected: usize,
+    /// The input that broke the parser and how, which ends the run.
+    pub failure: Option<(String, Outcome)>,
+}
+
+/// Feeds `iterations` mutated inputs to the parser of `day`, starting from the example in the
+/// workspace at `root` and small generated inputs. The same seed always tries the same inputs.
+pub fn fuzz(root: &Path, day: u8, seed: u64, iterations: usize) -> Summary {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let seeds = seeds(root, day, seed);
+
+    // The solvers' own panic messages would drown the summary
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let parser = Parser::new(move |text| days::parse(day, text), TIMEOUT);
+    let mut summary = Summary { parsed: 0, rejected: 0, failure: None };
+    for _ in 0..iterations {
+        let input = mutate(&mut rng, &seeds);
+        match parser.parse(&input) {
+            Outcome::Parsed => summary.parsed += 1,
+            Outcome::Rejected => summary.rejected += 1,
+            outcome => {
+                summary.failure = Some((input, outcome));
+                break;
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+    summary
+}
+
+/// Parses on a thread of its own, so a parser that never returns can be abandoned.
+struct Parser {
+    inputs: mpsc::Sender<String>,
+    outcomes: mpsc::Receiver<Outcome>,
+    timeout: Duration,
+}
+
+impl Parser {
+    fn new(parse: impl Fn(&str) -> Result<(), ParseError> + Send + 'static, timeout: Duration) -> Parser {
+        let (inputs, receiver) = mpsc::channel::<String>();
+        let (sender, outcomes) = mpsc::channel();
+        thread::spawn(move || {
+            for input in receiver {
+                let outcome = match panic::catch_unwind(AssertUnwindSafe(|| parse(&input))) {
+                    Ok(Ok(())) => Outcome::Parsed,
+                    Ok(Err(_)) => Outcome::Rejected,
+                    Err(payload) => Outcome::Panicked(solutions::panic_message(payload)),
+                };
+                if sender.send(outcome).is_err() {
+                    break;
+                }
+            }
+        });
+        Parser { inputs, outcomes, timeout }
+    }
+
+    fn parse(&self, input: &str) -> Outcome {
+        self.inputs.send(input.to_string()).expect("the parser thread outlives the parser");
+        self.outcomes.recv_timeout(self.timeout).unwrap_or(Outcome::Hung)
+    }
+}
+
+/// The inputs the mutations start from: generated ones, when the day has a generator, and the
+/// example. With neither the mutations start from an empty input.
+fn seeds(root: &Path, day: u8, seed: u64) -> Vec<String> {
+    let mut seeds: Vec<String> = (0..4).filter_map(|index| gen::generate(day, seed + index, 1 + index as usize * 3)).collect();
+    if let Ok(example) = fs::read_to_string(root.join(format!("day-{:02}/input/test.dat", day))) {
+        seeds.push(example);
+    }
+    if seeds.is_empty() {
+        seeds.push(String::new());
+    }
+    seeds
+}
+
+/// One of the seeds with a few random edits.
+fn mutate(rng: &mut ChaCha8Rng, seeds: &[String]) -> String {
+    let mut chars: Vec<char> = seeds.choose(rng).unwrap().chars().collect();
+
+    for _ in 0..rng.random_range(1..=4) {
+        let position = rng.random_range(0..=chars.len());
+        let end = (position + rng.random_range(0..8)).min(chars.len());
+        match rng.random_range(0..7) {
+            0 => {
+                chars.drain(position..end);
+            }
+            1 => chars.insert(position, *ALPHABET.choose(rng).unwrap()),
+            2 if position < chars.len() => chars[position] = *ALPHABET.choose(rng).unwrap(),
+            3 => {
+                let copy: Vec<char> = chars[position..end].to_vec();
+                chars.splice(position..position, copy);
+            }
+            4 => chars.truncate(position),
+            5 => {
+                chars.splice(position..position, NUMBERS.choose(rng).unwrap().chars());
+            }
+            _ => {
+                // A line from another seed, which mixes the formats of different parts of an input
+                let other = seeds.choose(rng).unwrap();
+                if let Some(line) = other.lines().collect::<Vec<_>>().choose(rng) {
+                    chars.splice(position..position, line.chars().chain(['\n']));
+                }
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stub::scratch;
+
+    use super::*;
+
+    fn check(day: u8) {
+        let summary = fuzz(Path::new(".."), day, 0, 2000);
+        if let Some((input, outcome)) = summary.failure {
+            panic!("day {}: {:?} on {:?}", day, outcome, input);
+        }
+        assert!(summary.parsed > 0, "day {}: no mutated input parsed", day);
+    }
+
+    #[test]
+    fn parsers_survive_fuzzing() {
+        for &day in days::DAYS {
+            check(day);
+        }
+    }
+
+    #[test]
+    fn catches_panics_and_hangs() {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let parser = Parser::new(|text| match text {
+            "panic" => panic!("index out of bounds"),
+            "loop" => loop { thread::park() },
+            "" => Err(ParseError::end_of_input(1, "a line")),
+            _ => Ok(()),
+        }, Duration::from_millis(100));
+
+        assert_eq!(parser.parse("fine"), Outcome::Parsed);
+        assert_eq!(parser.parse(""), Outcome::Rejected);
+        assert_eq!(parser.parse("panic"), Outcome::Panicked("index out of bounds".to_string()));
+        assert_eq!(parser.parse("loop"), Outcome::Hung);
+        panic::set_hook(hook);
+    }
+
+    #[test]
+    fn days_without_a_generator_start_from_the_example() {
+        let root = scratch("fuzz-seeds");
+        assert_eq!(seeds(&root, 13, 0), vec![String::new()]);
+
+        fs::create_dir_all(root.join("day-13/input")).unwrap();
+        fs::write(root.join("day-13/input/test.dat"), "1,2\n").unwrap();
+        assert_eq!(seeds(&root, 13, 0), vec!["1,2\n".to_string()]);
+        assert_eq!(seeds(&root, 1, 0).len(), 4);
+    }
+
+    #[test]
+    fn same_seed_same_inputs() {
+        let seeds = vec!["L68\nR48\n".to_string()];
+        let inputs = |seed| {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            (0..20).map(|_| mutate(&mut rng, &seeds)).collect::<Vec<_>>()
+        };
+        assert_eq!(inputs(3), inputs(3));
+        assert_ne!(inputs(3), inputs(4));
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Writes a random puzzle input for `day`. The same seed and size always give the same input,
+/// `size` is the number of lines, ranges, problems or machines the day's input is made of. `None`
+/// for a day without a generator.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let rng = &mut rng;
+
+    let text = match day {
+        1 => dial_instructions(rng, size),
+        2 => id_ranges(rng, size),
+        3 => battery_banks(rng, size),
+        4 => paper_rolls(rng, size),
+        5 => ingredients(rng, size),
+        6 => worksheet(rng, size),
+        7 => manifold(rng, size),
+        8 => junction_boxes(rng, size),
+        9 => red_tiles(rng, size),
+        10 => machines(rng, size),
+        11 => devices(rng, size),
+        12 => presents(rng, size),
+        _ => return None,
+    };
+    Some(text)
+}
+
+/// `L68` and `R48` rotations of the dial.
+fn dial_instructions(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+        format!("{}{}\n", direction, rng.random_range(1..1000))
+    }).collect()
+}
+
+/// Comma separated `lower-upper` ranges on a single line.
+fn id_ranges(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let ranges: Vec<String> = (0..size).map(|_| {
+        let lower: i64 = rng.random_range(1..10_000_000_000);
+        let upper = lower + rng.random_range(0..10_000);
+        format!("{}-{}", lower, upper)
+    }).collect();
+    format!("{}\n", ranges.join(","))
+}
+
+/// Banks of 100 batteries with joltages 1 to 9.
+fn battery_banks(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let mut bank: String = (0..100).map(|_| char::from(b'0' + rng.random_range(1..=9))).collect();
+        bank.push('\n');
+        bank
+    }).collect()
+}
+
+/// A square grid where `@` marks a roll of paper.
+fn paper_rolls(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let mut row: String = (0..size).map(|_| if rng.random_bool(0.6) { '@' } else { '.' }).collect();
+        row.push('\n');
+        row
+    }).collect()
+}
+
+/// Fresh `lower-upper` ranges, an empty line and as many ingredient IDs.
+fn ingredients(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        let lower: u64 = rng.random_range(1..1_000_000_000_000);
+        let upper = lower + rng.random_range(0..10_000_000_000);
+        writeln!(text, "{}-{}", lower, upper).unwrap();
+    }
+    text.push('\n');
+    for _ in 0..size {
+        writeln!(text, "{}", rng.random_range(1u64..1_010_000_000_000)).unwrap();
+    }
+    text
+}
+
+/// Problems of four numbers written in columns, each aligned left or right, above their operator.
+fn worksheet(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut rows = vec![String::new(); 5];
+    for problem in 0..size {
+        let numbers: Vec<String> = (0..4).map(|_| rng.random_range(1..10_000u32).to_string()).collect();
+        let width = numbers.iter().map(String::len).max().unwrap();
+        let align_left = rng.random_bool(0.5);
+
+        for (row, number) in rows.iter_mut().zip(&numbers) {
+            if problem > 0 {
+                row.push(' ');
+            }
+            if align_left {
+                write!(row, "{:<width$}", number).unwrap();
+            } else {
+                write!(row, "{:>width$}", number).unwrap();
+            }
+        }
+
+        let operators = &mut rows[4];
+        if problem > 0 {
+            operators.push(' ');
+        }
+        let operator = if rng.random_bool(0.5) { '+' } else { '*' };
+        write!(operators, "{:<width$}", operator).unwrap();
+    }
+
+    rows.iter().map(|row| format!("{}\n", row)).collect()
+}
+
+/// A manifold `size` splitter rows deep with the start in the middle of the top row. Like in the
+/// real ones, splitters are never next to each other or on the edge.
+fn manifold(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let width = 2 * size + 1;
+    let mut text = String::new();
+    writeln!(text, "{}S{}", ".".repeat(size), ".".repeat(size)).unwrap();
+    for _ in 0..size {
+        writeln!(text, "{}", ".".repeat(width)).unwrap();
+        let mut previous = false;
+        let row: String = (0..width).map(|x| {
+            previous = !previous && x > 0 && x < width - 1 && rng.random_bool(0.3);
+            if previous { '^' } else { '.' }
+        }).collect();
+        writeln!(text, "{}", row).unwrap();
+    }
+    text
+}
+
+/// Junction boxes at distinct `x,y,z` positions.
+fn junction_boxes(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut seen = HashSet::new();
+    let mut text = String::new();
+    while seen.len() < size {
+        let point: (u32, u32, u32) = (rng.random_range(0..100_000),
+                                      rng.random_range(0..100_000),
+                                      rng.random_range(0..100_000));
+        if seen.insert(point) {
+            writeln!(text, "{},{},{}", point.0, point.1, point.2).unwrap();
+        }
+    }
+    text
+}
+
+/// The corners of a closed rectilinear polygon: a skyline of `size` columns on a flat base.
+fn red_tiles(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut x: i64 = rng.random_range(1..1_000);
+    let base: i64 = rng.random_range(1..1_000);
+    let mut corners = vec![(x, base)];
+    let mut height = base;
+
+    for _ in 0..size {
+        // Every column differs in height from the previous one, so no corner is redundant
+        let mut next = height;
+        while next == height {
+            next = base + rng.random_range(1..100_000);
+        }
+        height = next;
+        corners.push((x, height));
+        x += rng.random_range(1..1_000);
+        corners.push((x, height));
+    }
+    corners.push((x, base));
+
+    corners.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect()
+}
+
+/// Machines whose lights and joltage levels can be reached by pressing the buttons.
+fn machines(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        let lights = rng.random_range(3..=10);
+        let button_count = rng.random_range(2..=lights + 3);
+        let buttons: Vec<Vec<usize>> = (0..button_count).map(|_| {
+            let mut button: Vec<usize> = (0..lights).filter(|_| rng.random_bool(0.4)).collect();
+            if button.is_empty() {
+                button.push(rng.random_range(0..lights));
+            }
+            button
+        }).collect();
+
+        // Work back from random presses, so both parts have a solution
+        let mut on = vec![false; lights];
+        let mut joltage = vec![0; lights];
+        for button in &buttons {
+            let presses = rng.random_range(0..20);
+            for &light in button {
+                on[light] ^= presses % 2 == 1;
+                joltage[light] += presses;
+            }
+        }
+
+        let on: String = on.iter().map(|&on| if on { '#' } else { '.' }).collect();
+        let buttons: Vec<String> = buttons.iter().map(|button| format!("({})", join(button))).collect();
+        writeln!(text, "[{}] {} {{{}}}", on, buttons.join(" "), join(&joltage)).unwrap();
+    }
+    text
+}
+
+/// A DAG of `size` devices running from `svr` and `you` past `fft` and `dac` to `out`, at most as
+/// many as there are three letter names.
+fn devices(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let special = ["svr", "you", "fft", "dac", "out"];
+    let size = size.min(26usize.pow(3) - special.len());
+    let mut names: Vec<String> = Vec::new();
+    let mut seen: HashSet<String> = special.iter().map(|name| name.to_string()).collect();
+    while names.len() < size {
+        let name: String = (0..3).map(|_| char::from(b'a' + rng.random_range(0..26))).collect();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    // The order of the devices is a topological order, outputs always point further down
+    let third = names.len() / 3;
+    let mut order: Vec<String> = vec!["svr".to_string(), "you".to_string()];
+    order.extend(names.drain(..third));
+    order.push("fft".to_string());
+    order.extend(names.drain(..third));
+    order.push("dac".to_string());
+    order.append(&mut names);
+    order.push("out".to_string());
+
+    let mut lines: Vec<String> = (0..order.len() - 1).map(|index| {
+        let later = &order[index + 1..];
+        let count = rng.random_range(1..=3.min(later.len()));
+        let outputs: Vec<&str> = later.choose_multiple(rng, count).map(String::as_str).collect();
+        format!("{}: {}\n", order[index], outputs.join(" "))
+    }).collect();
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+/// Six 3x3 presents followed by `size` regions to fit them in.
+fn presents(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut text = String::new();
+    let mut areas = Vec::new();
+    for index in 0..6 {
+        writeln!(text, "{}:", index).unwrap();
+        let mut area = 0;
+        for y in 0..3 {
+            let row: String = (0..3).map(|x| {
+                // The center keeps every present in one piece
+                let filled = (x, y) == (1, 1) || rng.random_bool(0.7);
+                area += usize::from(filled);
+                if filled { '#' } else { '.' }
+            }).collect();
+            writeln!(text, "{}", row).unwrap();
+        }
+        writeln!(text).unwrap();
+        areas.push(area);
+    }
+
+    for _ in 0..size {
+        let width = rng.random_range(4..=12);
+        let height = rng.random_range(4..=12);
+        let mut counts = [0; 6];
+        // Like in the real input, a region has plenty of room or too little. A tight fit is what
+        // the search takes ages on.
+        let crowded = rng.random_bool(0.25);
+        let room = width * height * 2 / 3;
+        let mut filled = 0;
+        loop {
+            let index = rng.random_range(0..6);
+            let full = if crowded { filled > width * height } else { filled + areas[index] > room || rng.random_bool(0.2) };
+            if full {
+                break;
+            }
+            counts[index] += 1;
+            filled += areas[index];
+        }
+        let counts: Vec<String> = counts.iter().map(usize::to_string).collect();
+        writeln!(text, "{}x{}: {}", width, height, counts.join(" ")).unwrap();
+    }
+    text
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values.iter().map(T::to_string).collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    fn round_trip<S: Solution>() {
+        for seed in 0..20 {
+            for size in [1, 2, 10, 50] {
+                // Not every day has a generator
+                let Some(text) = generate(S::DAY, seed, size) else { return };
+                let input = match S::parse(&text) {
+                    Ok(input) => input,
+                    Err(error) => panic!("day {} seed {} size {}: {}\n{}", S::DAY, seed, size, error, text),
+                };
+                // Parsing is not enough, the solvers have to cope with what the generator writes.
+                // The larger inputs would take the slow days too long.
+                if size <= 2 {
+                    S::part1(&input);
+                    S::part2(&input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generated_inputs_parse_and_solve() {
+        for &day in solutions::DAYS {
+            solutions::with_day!(day, S => round_trip::<S>());
+        }
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for day in 1..=12 {
+            assert_eq!(generate(day, 7, 10), generate(day, 7, 10));
+        }
+        assert_ne!(generate(1, 7, 10), generate(1, 8, 10));
+        assert_eq!(generate(13, 7, 10), None);
+    }
+
+    #[test]
+    fn devices_run_out_of_names() {
+        let text = generate(11, 7, 20_000).unwrap();
+        // Every name but `out` starts a line
+        assert_eq!(text.lines().count(), 26usize.pow(3) - 1);
+    }
+}
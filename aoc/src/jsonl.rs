@@ -0,0 +1,37 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+use common::Error;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Reads one value per line, a missing file has none.
+pub fn read<T: DeserializeOwned>(filename: &Path) -> Result<Vec<T>, Error> {
+    let text = match fs::read_to_string(filename) {
+        Ok(text) => text,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(io::Error::new(error.kind(), format!("{}: {}", filename.display(), error)).into()),
+    };
+    text.lines().enumerate().map(|(index, line)| {
+        serde_json::from_str(line).map_err(|error| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("{}:{}: {}", filename.display(), index + 1, error)).into()
+        })
+    }).collect()
+}
+
+/// Adds `values` to the end of the file, creating it and its directory when needed.
+pub fn append<T: Serialize>(filename: &Path, values: &[T]) -> Result<(), Error> {
+    if let Some(directory) = filename.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    let text: String = values.iter()
+                             .map(|value| format!("{}\n", serde_json::to_string(value).expect("plain data always serializes")))
+                             .collect();
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(filename)
+        .and_then(|mut file| file.write_all(text.as_bytes()))
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", filename.display(), error)).into())
+}
@@ -0,0 +1,377 @@
+use std::io::Read;
+use std::path::Path;
+use std::process::ExitCode;
+use std::{fs, io};
+
+use clap::{Parser, Subcommand};
+use common::{Answer, Error, Format};
+use client::Client;
+use shrink::Predicate;
+
+mod all;
+mod client;
+mod config;
+mod dashboard;
+mod days;
+mod fetch;
+mod fuzz;
+mod gen;
+mod jsonl;
+mod new;
+mod perf;
+mod serve;
+mod shrink;
+#[cfg(test)]
+mod stub;
+mod submit;
+mod verify;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: common::memory::Counting = common::memory::Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2025 solutions")]
+struct Cli {
+    /// Hide the progress bars
+    #[arg(long, short, global = true)]
+    quiet: bool,
+
+    /// Show the solvers' steps, -v for the main ones and -vv for all of them
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day
+    Run {
+        /// Day to run
+        #[arg(value_parser = days::registered, required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Run every day on its puzzle input in parallel and print a summary table
+        #[arg(long, conflicts_with_all = ["day", "input", "time", "mem"])]
+        all: bool,
+
+        /// Only run this part, both parts are run when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input files, `-` reads standard input. Defaults to day-NN/input/input.dat
+        #[arg(long, num_args = 1..)]
+        input: Vec<String>,
+
+        /// Output format: text, or json for one object per part and line
+        #[arg(long, default_value = "text")]
+        format: Format,
+
+        /// Also print how long parsing and each part took
+        #[arg(long)]
+        time: bool,
+
+        /// Count the allocations, bytes allocated, peak heap and peak resident memory of
+        /// parsing and each part
+        #[arg(long)]
+        mem: bool,
+    },
+    /// Check the solutions against the known answers
+    Verify {
+        /// Only verify this day
+        #[arg(long, value_parser = days::registered)]
+        day: Option<u8>,
+
+        /// Only verify this input file, e.g. test.dat
+        #[arg(long)]
+        input: Option<String>,
+
+        /// File with the expected answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: String,
+    },
+    /// Rebuild and rerun a day whenever its source or input changes
+    Watch {
+        /// Day to watch
+        #[arg(value_parser = days::registered)]
+        day: u8,
+
+        /// Input files in day-NN/input to run on, e.g. test.dat input.dat
+        #[arg(long, num_args = 1.., default_value = "test.dat")]
+        input: Vec<String>,
+    },
+    /// Show every day with its answers and run them in a terminal dashboard
+    Dashboard {
+        /// File with the expected answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: String,
+    },
+    /// Write a random input for a day
+    Gen {
+        /// Day to generate an input for
+        #[arg(long, value_parser = days::registered)]
+        day: u8,
+
+        /// Seed of the random generator, the same seed gives the same input
+        #[arg(long)]
+        seed: u64,
+
+        /// Number of lines, ranges, problems or machines to generate
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        size: u64,
+
+        /// File to write to, defaults to standard output
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// Create the crate of a new day from the template and register it with the workspace
+    New {
+        /// Day to create (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Feed mutated inputs to the parsers, which must reject what they cannot read without panicking
+    Fuzz {
+        /// Only fuzz this day, all days are fuzzed when omitted
+        #[arg(long, value_parser = days::registered)]
+        day: Option<u8>,
+
+        /// Seed of the mutations, the same seed tries the same inputs
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Number of inputs to try per day
+        #[arg(long, default_value_t = 100_000)]
+        iterations: usize,
+    },
+    /// Show how the run times of a day developed, flagging runs slower than the ones before them
+    PerfHistory {
+        /// Day to show
+        #[arg(value_parser = days::registered)]
+        day: u8,
+
+        /// Only show this part, both parts are shown when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file the runs were on, as given to `aoc run` [default: day-NN/input/input.dat]
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Runs slower than this many times the median of the runs before them are flagged
+        #[arg(long, default_value_t = 1.5)]
+        threshold: f64,
+
+        /// Number of most recent runs to show
+        #[arg(long, default_value_t = 20)]
+        last: usize,
+    },
+    /// Answer parts over HTTP on localhost: POST /day/{n}/part/{p} with the puzzle input as the body
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 8025)]
+        port: u16,
+    },
+    /// Answer a part for the puzzle input on standard input, printing the status and JSON body
+    /// `serve` replies with. `serve` and `shrink` run it in a child process.
+    #[command(hide = true)]
+    Answer {
+        #[arg(value_parser = days::registered)]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Download the puzzle input of a day to day-NN/input/input.dat, unless it is already there
+    Fetch {
+        /// Day to download
+        #[arg(value_parser = days::registered)]
+        day: u8,
+    },
+    /// Submit the answer to a part of a day, as computed from day-NN/input/input.dat
+    Submit {
+        /// Day to submit
+        #[arg(value_parser = days::registered)]
+        day: u8,
+
+        /// Part to submit (1-2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Shrink an input while it keeps failing, writing day-NN/input/<name>.min.dat
+    Shrink {
+        /// Day the input belongs to
+        #[arg(long, value_parser = days::registered)]
+        day: u8,
+
+        /// Input file to shrink
+        #[arg(long)]
+        input: String,
+
+        /// `panic`, or a shell command that fails on the input, with {} for the input file
+        #[arg(long)]
+        predicate: Predicate,
+    },
+}
+
+fn main() -> Result<ExitCode, Error> {
+    let cli = Cli::parse();
+    common::progress::set_quiet(cli.quiet);
+    common::init_tracing(cli.verbose);
+
+    match cli.command {
+        Command::Run { day, part, input, format, time, mem, .. } => {
+            let parts = part.map_or(vec![1, 2], |part| vec![part]);
+            let Some(day) = day else {
+                let cases = all::load_cases("answers.toml")?;
+                let passed = all::run_all(&parts, format, &cases, &perf::Log::new(perf::LOG));
+                return Ok(if passed { ExitCode::SUCCESS } else { ExitCode::FAILURE });
+            };
+            let inputs = if input.is_empty() { vec![days::default_input(day)] } else { input };
+            let log = perf::Log::new(perf::LOG);
+            if mem {
+                common::memory::enable();
+            }
+
+            for input in &inputs {
+                let reports = days::solve(day, &parts, input)?;
+                if inputs.len() > 1 && format == Format::Text {
+                    println!("{}:", input);
+                }
+                for report in &reports {
+                    match format {
+                        Format::Text => println!("Day {:02} part {}: {}", report.day, report.part, report.answer),
+                        Format::Json => println!("{}", report.to_json()),
+                    }
+                }
+                // The JSON output always carries the times, and with `--mem` the allocations
+                if time && format == Format::Text {
+                    common::print_times(&reports);
+                }
+                if mem && format == Format::Text {
+                    common::print_memory(&reports);
+                }
+                // Counting every allocation slows the solvers down, those times would only mislead
+                if !mem {
+                    log.record_or_warn(&reports);
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Verify { day, input, answers } => {
+            let cases: Vec<_> = common::answers::load(&answers)?
+                .into_iter()
+                .filter(|case| day.is_none_or(|day| case.day == day))
+                .filter(|case| input.as_ref().is_none_or(|input| case.input == *input))
+                .collect();
+
+            if verify::verify(&cases) {
+                Ok(ExitCode::SUCCESS)
+            } else {
+                Ok(ExitCode::FAILURE)
+            }
+        }
+        Command::Watch { day, input } => {
+            watch::watch(day, &input)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Dashboard { answers } => {
+            dashboard::dashboard(common::answers::load(&answers)?)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Gen { day, seed, size, output } => {
+            let text = gen::generate(day, seed, size as usize).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::Unsupported, format!("there is no generator for day {}", day))
+            })?;
+            match output {
+                Some(filename) => fs::write(&filename, text)
+                    .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", filename, error)))?,
+                None => print!("{}", text),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::New { day } => {
+            let (directory, changed) = new::new(Path::new("."), day)?;
+            println!("Created {}, registered in {}", directory, changed.join(", "));
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Fuzz { day, seed, iterations } => {
+            let days = day.map_or(days::DAYS.to_vec(), |day| vec![day]);
+            let mut failed = false;
+            for day in days {
+                let summary = fuzz::fuzz(Path::new("."), day, seed, iterations);
+                println!("Day {:02}: {} parsed, {} rejected", day, summary.parsed, summary.rejected);
+                if let Some((input, outcome)) = summary.failure {
+                    let filename = format!("day-{:02}/input/fuzz-{}.dat", day, seed);
+                    fs::write(&filename, input)
+                        .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", filename, error)))?;
+                    println!("  {:?}, the input is in {}", outcome, filename);
+                    failed = true;
+                }
+            }
+            Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+        }
+        Command::PerfHistory { day, part, input, threshold, last } => {
+            let entries: Vec<perf::Entry> = jsonl::read(Path::new(perf::LOG))?;
+            let input = input.unwrap_or_else(|| days::default_input(day));
+            let mut slow = false;
+            for part in part.map_or(vec![1, 2], |part| vec![part]) {
+                let trend = perf::trend(&entries, day, part, &input, threshold);
+                let shown = &trend[trend.len().saturating_sub(last)..];
+                perf::print_trend(day, part, &input, shown);
+                slow |= trend.last().is_some_and(|run| run.slow);
+            }
+            // Only the latest run decides, a slow run that has been fixed since is history
+            Ok(if slow { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+        }
+        Command::Serve { port } => {
+            let address = format!("127.0.0.1:{}", port);
+            let server = tiny_http::Server::http(&address)
+                .map_err(|error| io::Error::other(format!("{}: {}", address, error)))?;
+            println!("Listening on http://{}", address);
+            serve::serve(server);
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Answer { day, part } => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            let (status, value) = serve::answer(day, part, &text);
+            println!("{}\n{}", status, value);
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Fetch { day } => {
+            let filename = days::default_input(day);
+            let connect = || Client::from_config(&config::load(config::FILENAME)?);
+            if fetch::fetch(connect, day, &filename)? {
+                println!("Downloaded {}", filename);
+            } else {
+                println!("{} is already there", filename);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Submit { day, part } => {
+            let report = days::solve(day, &[part], &days::default_input(day))?.remove(0);
+            if report.answer == Answer::Unsolved {
+                let message = format!("day {} part {} is not solved yet", day, part);
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, message).into());
+            }
+
+            let client = Client::from_config(&config::load(config::FILENAME)?)?;
+            let mut history = submit::History::load(submit::HISTORY)?;
+            let answer = report.answer.to_string();
+            let outcome = submit::submit(&client, &mut history, day, part, &answer)?;
+            println!("Day {:02} part {}: {} is {}", day, part, answer, outcome);
+            match outcome {
+                submit::Outcome::Correct | submit::Outcome::AlreadySolved => Ok(ExitCode::SUCCESS),
+                _ => Ok(ExitCode::FAILURE),
+            }
+        }
+        Command::Shrink { day, input, predicate } => {
+            let output = shrink::shrink(day, &input, &predicate)?;
+            println!("Wrote {}", output);
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use std::path::Path;
+use std::{fs, io};
+
+use common::Error;
+
+/// The files of a new day, `{{nn}}` is replaced by the zero padded day and `{{day}}` by the day.
+const TEMPLATE: [(&str, &str); 5] = [
+    ("Cargo.toml", include_str!("../template/Cargo.toml.tpl")),
+    ("src/lib.rs", include_str!("../template/lib.rs.tpl")),
+    ("src/main.rs", include_str!("../template/main.rs.tpl")),
+    ("benches/day_{{nn}}.rs", include_str!("../template/bench.rs.tpl")),
+    ("input/test.dat", ""),
+];
+
+type Edit = fn(&str, u8) -> Result<String, String>;
+
+/// The files that register a day, relative to the workspace root, and how to add a day to them.
+const REGISTRY: [(&str, Edit); 4] = [
+    ("Cargo.toml", add_member),
+    ("solutions/Cargo.toml", add_dependency),
+    ("solutions/src/lib.rs", add_solution),
+    ("answers.toml", add_answers),
+];
+
+/// Creates the crate of `day` in the workspace at `root` and registers it with the workspace, the
+/// solutions that the runner and the C library share, and the answers file. Returns the directory of the crate and the files changed.
+pub fn new(root: &Path, day: u8) -> Result<(String, Vec<&'static str>), Error> {
+    let directory = format!("day-{:02}", day);
+    if root.join(&directory).exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", directory)).into());
+    }
+
+    // Every change is made in memory first, so a day that cannot be registered leaves no trace
+    let mut changes = Vec::new();
+    for (filename, edit) in REGISTRY {
+        let path = root.join(filename);
+        let text = fs::read_to_string(&path)
+            .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", filename, error)))?;
+        let text = edit(&text, day)
+            .map_err(|message| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", filename, message)))?;
+        changes.push((filename, text));
+    }
+
+    for (filename, template) in TEMPLATE {
+        let path = root.join(&directory).join(fill(filename, day));
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write(&path, &fill(template, day))?;
+    }
+    for (filename, text) in &changes {
+        write(&root.join(filename), text)?;
+    }
+
+    Ok((directory, changes.iter().map(|(filename, _)| *filename).collect()))
+}
+
+fn fill(template: &str, day: u8) -> String {
+    template.replace("{{nn}}", &format!("{:02}", day)).replace("{{day}}", &day.to_string())
+}
+
+fn write(path: &Path, text: &str) -> Result<(), Error> {
+    fs::write(path, text).map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path.display(), error)).into())
+}
+
+/// `    "day-NN",` in the workspace members.
+fn add_member(text: &str, day: u8) -> Result<String, String> {
+    insert_line(text, day, &format!("    \"day-{:02}\",", day), |line| {
+        line.trim().strip_prefix("\"day-")?.strip_suffix("\",")?.parse().ok()
+    })
+}
+
+/// `day-NN = { path = "../day-NN" }` in the dependencies of the solutions.
+fn add_dependency(text: &str, day: u8) -> Result<String, String> {
+    insert_line(text, day, &format!("day-{0:02} = {{ path = \"../day-{0:02}\" }}", day), |line| {
+        line.strip_prefix("day-")?.split_once(' ')?.0.parse().ok()
+    })
+}
+
+/// The re-export of the crate, the arm of `with_day!` and the entry in `DAYS`.
+fn add_solution(text: &str, day: u8) -> Result<String, String> {
+    let text = insert_line(text, day, &format!("pub use day_{:02};", day), |line| {
+        line.strip_prefix("pub use day_")?.strip_suffix(';')?.parse().ok()
+    })?;
+    let arm = format!("            {0} => {{ type $solution = $crate::day_{0:02}::Day{0:02}; $body }}", day);
+    let text = insert_line(&text, day, &arm, |line| {
+        line.trim().split_once(" => { type $solution = ")?.0.parse().ok()
+    })?;
+
+    let prefix = "pub const DAYS: &[u8] = &[";
+    let mut found = false;
+    let lines: Vec<String> = text.lines().map(|line| {
+        let Some(days) = line.strip_prefix(prefix).and_then(|rest| rest.strip_suffix("];")) else {
+            return line.to_string();
+        };
+        found = true;
+        let mut days: Vec<u8> = days.split(", ").filter_map(|day| day.parse().ok()).collect();
+        days.push(day);
+        days.sort();
+        let days: Vec<String> = days.iter().map(u8::to_string).collect();
+        format!("{}{}];", prefix, days.join(", "))
+    }).collect();
+
+    if !found {
+        return Err("found no list of days".to_string());
+    }
+    Ok(lines.iter().map(|line| format!("{}\n", line)).collect())
+}
+
+/// An empty `[day-NN]` table, the answers are added once they are known.
+fn add_answers(text: &str, day: u8) -> Result<String, String> {
+    let header = format!("[day-{:02}]", day);
+    if text.lines().any(|line| line == header) {
+        return Err(format!("day {} is already registered", day));
+    }
+    Ok(format!("{}\n\n{}\n\"test.dat\" = {{}}\n", text.trim_end(), header))
+}
+
+/// Inserts `new` among the lines that `day_of` finds a day in, keeping them ordered by day.
+fn insert_line(text: &str, day: u8, new: &str, day_of: impl Fn(&str) -> Option<u8>) -> Result<String, String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines.iter().enumerate()
+                                      .filter_map(|(index, line)| day_of(line).map(|day| (index, day)))
+                                      .collect();
+    if days.iter().any(|&(_, other)| other == day) {
+        return Err(format!("day {} is already registered", day));
+    }
+    let index = match days.iter().rev().find(|&&(_, other)| other < day) {
+        Some(&(index, _)) => index + 1,
+        None => days.first().ok_or("found no days to add to")?.0,
+    };
+
+    let mut lines = lines;
+    lines.insert(index, new);
+    Ok(lines.iter().map(|line| format!("{}\n", line)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::stub::scratch;
+
+    use super::*;
+
+    /// A copy of the files that register the days.
+    fn workspace(name: &str) -> PathBuf {
+        let root = scratch(name);
+        for (filename, _) in REGISTRY {
+            fs::create_dir_all(root.join(filename).parent().unwrap()).unwrap();
+            fs::copy(Path::new("..").join(filename), root.join(filename)).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn creates_and_registers_a_day() {
+        let root = workspace("new-day");
+
+        let (directory, changed) = new(&root, 13).unwrap();
+        assert_eq!(directory, "day-13");
+        assert_eq!(changed, vec!["Cargo.toml", "solutions/Cargo.toml", "solutions/src/lib.rs", "answers.toml"]);
+
+        let lib = fs::read_to_string(root.join("day-13/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day13;") && lib.contains("const DAY: u8 = 13;"));
+        assert!(lib.contains("common::answers::check::<Day13>(\"test.dat\")"));
+        assert_eq!(fs::read_to_string(root.join("day-13/input/test.dat")).unwrap(), "");
+        assert!(root.join("day-13/benches/day_13.rs").exists());
+
+        let members = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(members.contains("    \"day-12\",\n    \"day-13\",\n    \"aoc\",\n"));
+        let dependencies = fs::read_to_string(root.join("solutions/Cargo.toml")).unwrap();
+        assert!(dependencies.contains("day-12 = { path = \"../day-12\" }\nday-13 = { path = \"../day-13\" }\n"));
+        let solutions = fs::read_to_string(root.join("solutions/src/lib.rs")).unwrap();
+        assert!(solutions.contains("pub use day_12;\npub use day_13;\n"));
+        assert!(solutions.contains("            13 => { type $solution = $crate::day_13::Day13; $body }\n            _ =>"));
+        assert!(solutions.contains("pub const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];"));
+        let answers = fs::read_to_string(root.join("answers.toml")).unwrap();
+        assert!(answers.ends_with("\n\n[day-13]\n\"test.dat\" = {}\n"));
+    }
+
+    #[test]
+    fn refuses_to_overwrite_a_day() {
+        let root = workspace("new-existing");
+        new(&root, 13).unwrap();
+        fs::write(root.join("day-13/src/lib.rs"), "// solved").unwrap();
+
+        let error = new(&root, 13).unwrap_err();
+        assert_eq!(error.to_string(), "day-13 already exists");
+        assert_eq!(fs::read_to_string(root.join("day-13/src/lib.rs")).unwrap(), "// solved");
+
+        // A registered day without its directory is left alone as well
+        let registry = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        let error = new(&root, 5).unwrap_err();
+        assert_eq!(error.to_string(), "Cargo.toml: day 5 is already registered");
+        assert!(!root.join("day-05").exists());
+        assert_eq!(fs::read_to_string(root.join("Cargo.toml")).unwrap(), registry);
+    }
+}
@@ -0,0 +1,207 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use common::{Error, Report, STDIN};
+use serde::{Deserialize, Serialize};
+
+use crate::jsonl;
+
+pub const LOG: &str = ".aoc/perf.jsonl";
+
+/// How long one part took in one run, a line of the performance log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    /// `git describe` of the tree that ran, `-dirty` when it had uncommitted changes.
+    pub commit: Option<String>,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+}
+
+impl Entry {
+    fn total_ms(&self) -> f64 {
+        self.parse_ms + self.solve_ms
+    }
+}
+
+/// Appends every run of `aoc run` to the performance log.
+pub struct Log {
+    filename: PathBuf,
+    commit: Option<String>,
+}
+
+impl Log {
+    pub fn new(filename: impl Into<PathBuf>) -> Log {
+        Log { filename: filename.into(), commit: commit() }
+    }
+
+    /// Records `reports`, except those read from standard input, which has no name to compare by.
+    pub fn record(&self, reports: &[Report]) -> Result<(), Error> {
+        let time = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+        let entries: Vec<Entry> = reports.iter().filter(|report| report.input != STDIN).map(|report| Entry {
+            time,
+            commit: self.commit.clone(),
+            day: report.day,
+            part: report.part,
+            input: report.input.clone(),
+            parse_ms: report.parse_time.as_micros() as f64 / 1000.0,
+            solve_ms: report.solve_time.as_micros() as f64 / 1000.0,
+        }).collect();
+        jsonl::append(&self.filename, &entries)
+    }
+
+    /// Records `reports` like `record`, but only warns when the log cannot be written, the answers
+    /// matter more than their times.
+    pub fn record_or_warn(&self, reports: &[Report]) {
+        if let Err(error) = self.record(reports) {
+            eprintln!("warning: the run is not in the performance log: {}", error);
+        }
+    }
+}
+
+fn commit() -> Option<String> {
+    let output = Command::new("git").args(["describe", "--always", "--dirty"]).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// A run of one part next to the median of the runs before it.
+pub struct Trend<'a> {
+    pub entry: &'a Entry,
+    pub median_ms: Option<f64>,
+    /// Slower than the threshold allows.
+    pub slow: bool,
+}
+
+/// The runs of `part` of `day` on the input file `input`, oldest first, each compared with the
+/// median of the runs before it. Runs more than `threshold` times that median are slow.
+pub fn trend<'a>(entries: &'a [Entry], day: u8, part: u8, input: &str, threshold: f64) -> Vec<Trend<'a>> {
+    let runs: Vec<&Entry> = entries.iter()
+                                   .filter(|entry| entry.day == day && entry.part == part)
+                                   .filter(|entry| Path::new(&entry.input) == Path::new(input))
+                                   .collect();
+    runs.iter().enumerate().map(|(index, entry)| {
+        let median_ms = median(runs[..index].iter().map(|entry| entry.total_ms()).collect());
+        Trend { entry, median_ms, slow: median_ms.is_some_and(|median| entry.total_ms() > threshold * median) }
+    }).collect()
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let middle = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) { (values[middle - 1] + values[middle]) / 2.0 } else { values[middle] })
+}
+
+/// Prints the trend of one part with a bar per run, scaled to the slowest run shown.
+pub fn print_trend(day: u8, part: u8, input: &str, trend: &[Trend]) {
+    println!("Day {:02} part {} on {}", day, part, input);
+    if trend.is_empty() {
+        println!("  no runs yet");
+        return;
+    }
+
+    let slowest = trend.iter().map(|run| run.entry.total_ms()).fold(0.0, f64::max);
+    println!("{:<16} {:<20} {:>12} {:>12}  {:<20}", "Date", "Commit", "Time", "Median", "");
+    for run in trend {
+        let width = if slowest > 0.0 { (20.0 * run.entry.total_ms() / slowest).round() as usize } else { 0 };
+        println!("{:<16} {:<20} {:>9.3} ms {:>12}  {:<20}{}",
+                 date(run.entry.time),
+                 run.entry.commit.as_deref().unwrap_or("-"),
+                 run.entry.total_ms(),
+                 run.median_ms.map_or(String::new(), |median| format!("{:.3} ms", median)),
+                 "#".repeat(width),
+                 if run.slow { "  SLOW" } else { "" });
+    }
+    println!();
+}
+
+/// `YYYY-MM-DD hh:mm` in UTC.
+fn date(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64;
+    let minutes = seconds % 86_400 / 60;
+
+    // Days to a civil date, after Howard Hinnant's `civil_from_days`
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, minutes / 60, minutes % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use common::Answer;
+
+    use crate::stub::{report, scratch};
+
+    use super::*;
+
+    fn entry(part: u8, input: &str, solve_ms: f64) -> Entry {
+        Entry { time: 0, commit: None, day: 3, part, input: input.to_string(), parse_ms: 0.0, solve_ms }
+    }
+
+    #[test]
+    fn flags_runs_slower_than_the_median_before_them() {
+        let entries = vec![
+            entry(1, "day-03/input/input.dat", 10.0),
+            entry(1, "day-03/input/input.dat", 12.0),
+            entry(2, "day-03/input/input.dat", 500.0),
+            entry(1, "day-03/input/test.dat", 500.0),
+            entry(1, "day-03/input/input.dat", 14.0),
+            entry(1, "day-03/input/input.dat", 20.0),
+            entry(1, "elsewhere/input.dat", 500.0),
+        ];
+
+        let trend = trend(&entries, 3, 1, "day-03/input/input.dat", 1.5);
+        let medians: Vec<Option<f64>> = trend.iter().map(|run| run.median_ms).collect();
+        assert_eq!(medians, vec![None, Some(10.0), Some(11.0), Some(12.0)]);
+        let slow: Vec<bool> = trend.iter().map(|run| run.slow).collect();
+        assert_eq!(slow, vec![false, false, false, true]);
+    }
+
+    #[test]
+    fn records_every_part_of_a_run() {
+        let filename = scratch("perf").join("perf.jsonl");
+        let report = |part, input| report(3, part, input, Answer::Number(357));
+
+        let log = Log::new(&filename);
+        log.record(&[report(1, "day-03/input/test.dat"), report(2, "day-03/input/test.dat")]).unwrap();
+        log.record(&[report(1, STDIN)]).unwrap();
+
+        let entries: Vec<Entry> = jsonl::read(&filename).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[1].part, entries[1].parse_ms, entries[1].solve_ms), (2, 1.5, 0.25));
+    }
+
+    #[test]
+    fn an_unwritable_log_only_warns() {
+        // A file where the log's directory should be
+        let taken = scratch("perf-unwritable");
+        fs::write(&taken, "").unwrap();
+        let log = Log::new(taken.join("perf.jsonl"));
+        assert!(log.record(&[]).is_err());
+        log.record_or_warn(&[]);
+    }
+
+    #[test]
+    fn dates_are_utc() {
+        assert_eq!(date(0), "1970-01-01 00:00");
+        assert_eq!(date(951_782_400 + 3_600 + 60), "2000-02-29 01:01");
+        assert_eq!(date(1_764_547_200), "2025-12-01 00:00");
+    }
+}
@@ -0,0 +1,132 @@
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::{Command, Stdio};
+use std::{env, thread};
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::days;
+
+/// Answers requests until the process ends, each on a thread of its own, so a slow day holds up
+/// nobody. `POST /day/{n}/part/{p}` with the puzzle input as the body answers a part, in a child
+/// process, as a solver that overflows its stack aborts the process it runs in.
+pub fn serve(server: Server) {
+    for request in server.incoming_requests() {
+        thread::spawn(move || respond(request));
+    }
+}
+
+fn respond(mut request: Request) {
+    let mut body = Vec::new();
+    let (status, value) = match request.as_reader().read_to_end(&mut body) {
+        Ok(_) => route(request.method(), request.url(), body),
+        Err(error) => (400, error_body(format!("reading the request: {}", error))),
+    };
+    let header = Header::from_bytes("Content-Type", "application/json").expect("the header is valid");
+    let response = Response::from_string(value.to_string()).with_status_code(status).with_header(header);
+    // The client hung up, nobody is left to tell
+    let _ = request.respond(response);
+}
+
+/// The status and JSON body that answer `method` on `url`.
+fn route(method: &Method, url: &str, body: Vec<u8>) -> (u16, Value) {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let ["day", day, "part", part] = segments[..] else {
+        return (404, error_body(format!("no such endpoint: {}", path)));
+    };
+    if *method != Method::Post {
+        return (405, error_body(format!("{} takes the puzzle input by POST", path)));
+    }
+    let Some(day) = day.parse().ok().filter(|day| days::DAYS.contains(day)) else {
+        return (404, error_body(format!("no solution for day {}", day)));
+    };
+    let Some(part) = part.parse().ok().filter(|part| matches!(part, 1 | 2)) else {
+        return (404, error_body(format!("no part {}, there are parts 1 and 2", part)));
+    };
+    let Ok(text) = String::from_utf8(body) else {
+        return (400, error_body("the puzzle input is not UTF-8".to_string()));
+    };
+    answer_in_child(day, part, &text)
+}
+
+/// What `answer` gives, from `aoc answer` in a child process. A child that dies without an answer
+/// is a 500 too.
+pub fn answer_in_child(day: u8, part: u8, text: &str) -> (u16, Value) {
+    let output = env::current_exe().and_then(|program| {
+        let mut child = Command::new(program)
+            .args(["answer", &day.to_string(), &part.to_string()])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        // The child reads all of its input before it answers, so nothing waits on the other
+        child.stdin.take().expect("the input is piped").write_all(text.as_bytes())?;
+        child.wait_with_output()
+    });
+    let output = match output {
+        Ok(output) => output,
+        Err(error) => return (500, error_body(format!("running day {} part {}: {}", day, part, error))),
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let answer = stdout.split_once('\n').and_then(|(status, value)| {
+        Some((status.parse().ok()?, serde_json::from_str(value).ok()?))
+    });
+    answer.unwrap_or_else(|| (500, error_body(format!("day {} part {} crashed: {}", day, part, output.status))))
+}
+
+/// The status and JSON body that answer `part` of `day` for the puzzle input `text`.
+pub fn answer(day: u8, part: u8, text: &str) -> (u16, Value) {
+    match panic::catch_unwind(AssertUnwindSafe(|| days::run_text(day, &[part], "request", text))) {
+        Ok(Ok(mut reports)) => {
+            let mut value = serde_json::to_value(reports.remove(0)).expect("a report always serializes");
+            value.as_object_mut().expect("a report is an object").remove("input");
+            (200, value)
+        }
+        Ok(Err(error)) => (422, json!({
+            "error": error.to_string(),
+            "line": error.line,
+            "column": error.column,
+            "expected": error.expected,
+            "found": error.found,
+        })),
+        Err(payload) => (500, error_body(format!("day {} part {} panicked: {}", day, part, solutions::panic_message(payload)))),
+    }
+}
+
+fn error_body(message: String) -> Value {
+    json!({ "error": message })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(url: &str, body: &str) -> (u16, Value) {
+        route(&Method::Post, url, body.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_input() {
+        let (status, value) = answer(1, 1, "L68\nX12\n");
+        assert_eq!(status, 422);
+        assert_eq!(value, json!({
+            "error": "2:1: expected 'L' or 'R', found 'X'",
+            "line": 2,
+            "column": 1,
+            "expected": "'L' or 'R'",
+            "found": "'X'",
+        }));
+    }
+
+    #[test]
+    fn rejects_what_is_not_a_part() {
+        assert_eq!(post("/day/13/part/1", "").0, 404);
+        assert_eq!(post("/day/x/part/1", "").0, 404);
+        assert_eq!(post("/day/1/part/3", "").0, 404);
+        assert_eq!(post("/days", "").0, 404);
+        assert_eq!(route(&Method::Get, "/day/1/part/1", Vec::new()).0, 405);
+        assert_eq!(route(&Method::Post, "/day/1/part/1", vec![0xff, 0xfe]).0, 400);
+    }
+}
@@ -0,0 +1,251 @@
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+use std::{fs, io};
+
+use common::Error;
+
+use crate::{days, serve};
+
+/// What makes an input interesting to keep shrinking.
+#[derive(Debug, Clone)]
+pub enum Predicate {
+    /// Solving one of the parts panics, or aborts, e.g. on a stack overflow.
+    Panic,
+    /// A shell command fails, `{}` in the command is replaced by the path of the candidate input.
+    Command(String),
+}
+
+impl FromStr for Predicate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "panic" => Ok(Predicate::Panic),
+            _ if s.contains("{}") => Ok(Predicate::Command(s.to_string())),
+            _ => Err(format!("expected panic or a command containing {{}}, found '{}'", s)),
+        }
+    }
+}
+
+/// An input cut into the parts the minimizer may drop, in the day's own format.
+struct Split {
+    kind: Kind,
+    /// Text before the parts that is always kept, e.g. the presents of day 12.
+    header: String,
+    parts: Vec<String>,
+}
+
+enum Kind {
+    /// One part per line.
+    Lines,
+    /// The comma separated ranges of day 2.
+    Ranges,
+    /// The worksheet problems of day 6, each a block of columns.
+    Problems,
+    /// The `device: output` edges of day 11.
+    Edges,
+}
+
+impl Split {
+    fn new(day: u8, text: &str) -> Split {
+        match day {
+            2 => Split {
+                kind: Kind::Ranges,
+                header: String::new(),
+                parts: text.trim_end().split(',').map(str::to_string).collect(),
+            },
+            6 => Split { kind: Kind::Problems, header: String::new(), parts: problems(text) },
+            11 => Split {
+                kind: Kind::Edges,
+                header: String::new(),
+                parts: text.lines().flat_map(|line| {
+                    let (device, outputs) = line.split_once(':').unwrap_or((line, ""));
+                    outputs.split_whitespace().map(move |output| format!("{}: {}", device, output))
+                }).collect(),
+            },
+            12 => {
+                // Only the regions are dropped, removing presents would renumber them
+                let regions = text.lines().position(|line| line.contains('x')).unwrap_or(text.lines().count());
+                Split {
+                    kind: Kind::Lines,
+                    header: text.lines().take(regions).map(|line| format!("{}\n", line)).collect(),
+                    parts: text.lines().skip(regions).map(str::to_string).collect(),
+                }
+            }
+            _ => Split { kind: Kind::Lines, header: String::new(), parts: text.lines().map(str::to_string).collect() },
+        }
+    }
+
+    fn join(&self, parts: &[String]) -> String {
+        let body = match self.kind {
+            Kind::Lines => parts.iter().map(|line| format!("{}\n", line)).collect(),
+            Kind::Ranges => format!("{}\n", parts.join(",")),
+            Kind::Problems => {
+                let rows = parts.first().map_or(0, |problem| problem.lines().count());
+                (0..rows).map(|row| {
+                    let columns: Vec<&str> = parts.iter().map(|problem| problem.lines().nth(row).unwrap()).collect();
+                    format!("{}\n", columns.join(" "))
+                }).collect()
+            }
+            Kind::Edges => {
+                let mut devices: Vec<(&str, Vec<&str>)> = Vec::new();
+                for edge in parts {
+                    let (device, output) = edge.split_once(": ").unwrap();
+                    match devices.iter_mut().find(|(name, _)| *name == device) {
+                        Some((_, outputs)) => outputs.push(output),
+                        None => devices.push((device, vec![output])),
+                    }
+                }
+                devices.iter().map(|(device, outputs)| format!("{}: {}\n", device, outputs.join(" "))).collect()
+            }
+        };
+        format!("{}{}", self.header, body)
+    }
+}
+
+/// Cuts the worksheet at the columns that are blank in every row.
+fn problems(text: &str) -> Vec<String> {
+    let rows: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let blank = |column: usize| rows.iter().all(|row| row.get(column).is_none_or(|c| *c == ' '));
+
+    let mut problems = Vec::new();
+    let mut start = 0;
+    for column in 0..=width {
+        if column == width || blank(column) {
+            if column > start {
+                let block: String = rows.iter().map(|row| {
+                    let cells: String = (start..column).map(|x| row.get(x).copied().unwrap_or(' ')).collect();
+                    format!("{}\n", cells)
+                }).collect();
+                problems.push(block);
+            }
+            start = column + 1;
+        }
+    }
+    problems
+}
+
+/// Delta debugging: drops ever smaller chunks of `parts` for as long as `interesting` holds.
+fn ddmin(mut parts: Vec<String>, mut interesting: impl FnMut(&[String]) -> bool) -> Vec<String> {
+    let mut chunks = 2;
+    while parts.len() >= 2 {
+        let size = parts.len().div_ceil(chunks);
+        let mut reduced = false;
+
+        for start in (0..parts.len()).step_by(size) {
+            let end = (start + size).min(parts.len());
+            let chunk = parts[start..end].to_vec();
+            let complement: Vec<String> = parts[..start].iter().chain(&parts[end..]).cloned().collect();
+
+            if interesting(&chunk) {
+                parts = chunk;
+                chunks = 2;
+                reduced = true;
+                break;
+            }
+            if interesting(&complement) {
+                parts = complement;
+                chunks = (chunks - 1).max(2);
+                reduced = true;
+                break;
+            }
+        }
+
+        if !reduced {
+            if chunks >= parts.len() {
+                break;
+            }
+            chunks = (chunks * 2).min(parts.len());
+        }
+    }
+    parts
+}
+
+/// Shrinks the input in `filename` while it keeps failing `predicate` and writes the result to
+/// `<name>.min.dat` in the day's input directory. Returns the path written to.
+pub fn shrink(day: u8, filename: &str, predicate: &Predicate) -> Result<String, Error> {
+    let text = fs::read_to_string(filename)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", filename, error)))?;
+    let name = Path::new(filename).file_stem().and_then(|stem| stem.to_str()).unwrap_or("input");
+    let output = format!("day-{:02}/input/{}.min.dat", day, name);
+    let candidate = format!("day-{:02}/input/{}.candidate.dat", day, name);
+
+    let split = Split::new(day, &text);
+    let fails = |text: &str| {
+        // Inputs the parser rejects fail for the wrong reason
+        days::parse(day, text).is_ok() && match predicate {
+            Predicate::Panic => panics(day, text),
+            Predicate::Command(command) => {
+                fs::write(&candidate, text).is_ok() && command_fails(command, &candidate)
+            }
+        }
+    };
+
+    let result = if fails(&split.join(&split.parts)) {
+        let parts = ddmin(split.parts.clone(), |parts| fails(&split.join(parts)));
+        println!("Shrunk {} parts to {}", split.parts.len(), parts.len());
+        fs::write(&output, split.join(&parts))
+            .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", output, error)).into())
+    } else {
+        Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{}: does not fail the predicate", filename)).into())
+    };
+
+    let _ = fs::remove_file(&candidate);
+    result.map(|_| output)
+}
+
+/// Each part runs in a child process, which a stack overflow aborts on its own.
+fn panics(day: u8, text: &str) -> bool {
+    [1, 2].into_iter().any(|part| serve::answer_in_child(day, part, text).0 == 500)
+}
+
+fn command_fails(command: &str, candidate: &str) -> bool {
+    Command::new("sh")
+        .arg("-c")
+        .arg(command.replace("{}", candidate))
+        .status()
+        .is_ok_and(|status| !status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn ddmin_finds_the_failing_parts() {
+        let parts: Vec<String> = (0..50).map(|n| n.to_string()).collect();
+        let minimal = ddmin(parts, |parts| parts.contains(&"7".to_string()) && parts.contains(&"42".to_string()));
+        assert_eq!(minimal, strings(&["7", "42"]));
+    }
+
+    #[test]
+    fn examples_survive_splitting() {
+        for day in 1..=12 {
+            let filename = format!("../day-{:02}/input/test.dat", day);
+            let text = fs::read_to_string(&filename).unwrap();
+            let split = Split::new(day, &text);
+            let joined = split.join(&split.parts);
+            assert!(days::parse(day, &joined).is_ok(), "{}:\n{}", filename, joined);
+        }
+    }
+
+    #[test]
+    fn worksheet_problems_are_dropped_as_columns() {
+        let split = Split::new(6, "123 328\n 45 64 \n*   +  \n");
+        assert_eq!(split.parts.len(), 2);
+        assert_eq!(split.join(&split.parts[1..]), "328\n64 \n+  \n");
+    }
+
+    #[test]
+    fn edges_are_dropped_one_by_one() {
+        let split = Split::new(11, "you: a b\na: out\nb: out\n");
+        assert_eq!(split.parts, strings(&["you: a", "you: b", "a: out", "b: out"]));
+        assert_eq!(split.join(&strings(&["you: b", "b: out"])), "you: b\nb: out\n");
+    }
+}
@@ -0,0 +1,70 @@
+//! A stand-in for the Advent of Code server and the other fixtures of the tests.
+
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::Duration;
+use std::{env, fs, process, thread};
+
+use common::{Answer, Report};
+use tiny_http::{Header, Response, Server};
+
+/// A request as the stub received it.
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub url: String,
+    pub cookie: String,
+    pub body: String,
+}
+
+pub fn reply(status: u16, body: &str) -> Response<Cursor<Vec<u8>>> {
+    Response::from_string(body).with_status_code(status)
+}
+
+pub fn rate_limited(seconds: u64) -> Response<Cursor<Vec<u8>>> {
+    reply(429, "Slow down").with_header(Header::from_bytes("Retry-After", seconds.to_string()).unwrap())
+}
+
+/// Starts a server answering `responses` in turn. Returns its URL and the requests it received.
+pub fn serve(responses: Vec<Response<Cursor<Vec<u8>>>>) -> (String, mpsc::Receiver<Request>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        for response in responses {
+            let mut request = server.recv().unwrap();
+            let cookie = request.headers().iter()
+                                .find(|header| header.field.equiv("Cookie"))
+                                .map(|header| header.value.to_string())
+                                .unwrap_or_default();
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            sender.send(Request { url: request.url().to_string(), cookie, body }).unwrap();
+            request.respond(response).unwrap();
+        }
+    });
+
+    (url, receiver)
+}
+
+/// An empty directory of its own for a test.
+pub fn scratch(name: &str) -> PathBuf {
+    let directory = env::temp_dir().join(format!("aoc-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    directory
+}
+
+/// The report of a run of `part` of `day` on `input` that parsed in 1.5 ms and solved in 0.25 ms.
+pub fn report(day: u8, part: u8, input: &str, answer: Answer) -> Report {
+    Report {
+        day,
+        part,
+        input: input.to_string(),
+        answer,
+        parse_time: Duration::from_micros(1500),
+        solve_time: Duration::from_micros(250),
+        parse_memory: None,
+        solve_memory: None,
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use common::Error;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, Reply, YEAR};
+use crate::jsonl;
+
+pub const HISTORY: &str = ".aoc/history.jsonl";
+
+/// How often an answer is posted while the server keeps rate limiting.
+const ATTEMPTS: u32 = 5;
+
+/// First wait when the server does not say how long to wait, doubled on every attempt.
+const BACKOFF: Duration = Duration::from_secs(5);
+
+/// How the server judged an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The part was solved before, the server no longer checks answers to it.
+    AlreadySolved,
+    RateLimited,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Outcome::Correct => "the right answer",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wrong => "wrong",
+            Outcome::AlreadySolved => "not checked, the part is already solved",
+            Outcome::RateLimited => "rate limited",
+        })
+    }
+}
+
+/// One answer posted to the server, a line of the history file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// Every answer ever submitted, kept as one JSON object per line.
+pub struct History {
+    filename: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Reads the history in `filename`, a missing file is an empty history.
+    pub fn load(filename: impl Into<PathBuf>) -> Result<History, Error> {
+        let filename = filename.into();
+        let attempts = jsonl::read(&filename)?;
+        Ok(History { filename, attempts })
+    }
+
+    /// The earlier attempt that settles `answer` without asking the server: the accepted answer,
+    /// the same answer rejected before, or a bound it is not within.
+    fn settled(&self, day: u8, part: u8, answer: &str) -> Option<&Attempt> {
+        let number = answer.parse::<i128>().ok();
+        self.attempts.iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part)
+            .find(|attempt| {
+                let bound = attempt.answer.parse::<i128>().ok().zip(number);
+                match attempt.outcome {
+                    Outcome::Correct => true,
+                    Outcome::Wrong => attempt.answer == answer,
+                    Outcome::TooHigh => bound.is_some_and(|(high, number)| number >= high),
+                    Outcome::TooLow => bound.is_some_and(|(low, number)| number <= low),
+                    Outcome::AlreadySolved | Outcome::RateLimited => false,
+                }
+            })
+    }
+
+    fn record(&mut self, day: u8, part: u8, answer: &str, outcome: Outcome) -> Result<(), Error> {
+        let time = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+        let attempt = Attempt { day, part, answer: answer.to_string(), outcome, time };
+        jsonl::append(&self.filename, std::slice::from_ref(&attempt))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+/// Posts `answer` to `part` of `day`, waiting and trying again while rate limited. Every attempt
+/// is recorded in `history`, and answers the history already settles are refused.
+pub fn submit(client: &Client, history: &mut History, day: u8, part: u8, answer: &str) -> Result<Outcome, Error> {
+    if let Some(attempt) = history.settled(day, part, answer) {
+        let message = format!("refusing to submit {}, {} was {}", answer, attempt.answer, attempt.outcome);
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, message).into());
+    }
+
+    let path = format!("/{}/day/{}/answer", YEAR, day);
+    let level = part.to_string();
+    let mut backoff = BACKOFF;
+    for attempt in 1..=ATTEMPTS {
+        let (outcome, wait) = match client.post(&path, &[("level", &level), ("answer", answer)])? {
+            Reply::Page(page) => judge(&page)?,
+            Reply::RateLimited(wait) => (Outcome::RateLimited, wait),
+        };
+        history.record(day, part, answer, outcome)?;
+        if outcome != Outcome::RateLimited || attempt == ATTEMPTS {
+            return Ok(outcome);
+        }
+
+        let wait = wait.map_or(backoff, Duration::from_secs);
+        eprintln!("Rate limited, trying again in {}s", wait.as_secs());
+        thread::sleep(wait);
+        backoff *= 2;
+    }
+    unreachable!("the last attempt returns")
+}
+
+/// Reads the outcome from the answer page, with the seconds left to wait when rate limited.
+fn judge(page: &str) -> Result<(Outcome, Option<u64>), Error> {
+    let outcome = if page.contains("That's the right answer") {
+        Outcome::Correct
+    } else if page.contains("That's not the right answer") {
+        if page.contains("answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if page.contains("You gave an answer too recently") {
+        return Ok((Outcome::RateLimited, wait(page)));
+    } else if page.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "the answer page says neither right nor wrong").into());
+    };
+    Ok((outcome, None))
+}
+
+/// Adds up `You have 1m 20s left to wait`.
+fn wait(page: &str) -> Option<u64> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let (_, time) = before.rsplit_once("You have ")?;
+    time.split_whitespace().map(|amount| {
+        let seconds = |unit: char, factor: u64| {
+            amount.strip_suffix(unit).and_then(|number| number.parse::<u64>().ok()).map(|number| number * factor)
+        };
+        seconds('h', 3600).or_else(|| seconds('m', 60)).or_else(|| seconds('s', 1))
+    }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use crate::stub::{self, rate_limited, reply, scratch};
+
+    use super::*;
+
+    const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.</p></article>";
+    const TOO_RECENTLY: &str = "<article><p>You gave an answer too recently; you have to wait after submitting \
+                                an answer before trying again.  You have 1m 20s left to wait.</p></article>";
+
+    fn client(url: &str, directory: &std::path::Path) -> Client {
+        Client::new(url, "secret", Duration::ZERO, directory.join("last-request"))
+    }
+
+    #[test]
+    fn posts_the_answer_and_records_it() {
+        let (url, requests) = stub::serve(vec![reply(200, RIGHT)]);
+        let directory = scratch("submit-right");
+        let mut history = History::load(directory.join("history.jsonl")).unwrap();
+
+        assert_eq!(submit(&client(&url, &directory), &mut history, 3, 2, "1234").unwrap(), Outcome::Correct);
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.url, "/2025/day/3/answer");
+        assert_eq!(request.cookie, "session=secret");
+        assert_eq!(request.body, "level=2&answer=1234");
+        let reloaded = History::load(directory.join("history.jsonl")).unwrap();
+        assert_eq!(reloaded.attempts, history.attempts);
+        assert_eq!(reloaded.attempts[0].outcome, Outcome::Correct);
+    }
+
+    #[test]
+    fn known_wrong_answers_are_not_resubmitted() {
+        let (url, requests) = stub::serve(vec![reply(200, TOO_HIGH), reply(200, RIGHT)]);
+        let directory = scratch("submit-wrong");
+        let client = client(&url, &directory);
+        let mut history = History::load(directory.join("history.jsonl")).unwrap();
+
+        assert_eq!(submit(&client, &mut history, 1, 1, "500").unwrap(), Outcome::TooHigh);
+        let error = submit(&client, &mut history, 1, 1, "500").unwrap_err();
+        assert_eq!(error.to_string(), "refusing to submit 500, 500 was too high");
+        let error = submit(&client, &mut history, 1, 1, "501").unwrap_err();
+        assert_eq!(error.to_string(), "refusing to submit 501, 500 was too high");
+
+        // The other part and lower answers still go through
+        let mut history = History::load(directory.join("history.jsonl")).unwrap();
+        assert!(history.settled(1, 2, "501").is_none());
+        assert_eq!(submit(&client, &mut history, 1, 1, "499").unwrap(), Outcome::Correct);
+        let error = submit(&client, &mut history, 1, 1, "12").unwrap_err();
+        assert_eq!(error.to_string(), "refusing to submit 12, 499 was the right answer");
+
+        assert_eq!(requests.try_iter().count(), 2);
+    }
+
+    #[test]
+    fn backs_off_while_rate_limited() {
+        let (url, requests) = stub::serve(vec![rate_limited(1), reply(200, RIGHT)]);
+        let directory = scratch("submit-limited");
+        let mut history = History::load(directory.join("history.jsonl")).unwrap();
+
+        let start = Instant::now();
+        assert_eq!(submit(&client(&url, &directory), &mut history, 5, 1, "7").unwrap(), Outcome::Correct);
+        assert!(start.elapsed() >= Duration::from_secs(1));
+
+        let outcomes: Vec<Outcome> = history.attempts.iter().map(|attempt| attempt.outcome).collect();
+        assert_eq!(outcomes, vec![Outcome::RateLimited, Outcome::Correct]);
+        assert_eq!(requests.try_iter().count(), 2);
+    }
+
+    #[test]
+    fn reads_the_answer_page() {
+        assert_eq!(judge(RIGHT).unwrap(), (Outcome::Correct, None));
+        assert_eq!(judge(TOO_HIGH).unwrap(), (Outcome::TooHigh, None));
+        assert_eq!(judge(TOO_RECENTLY).unwrap(), (Outcome::RateLimited, Some(80)));
+        assert!(judge("<html>Puzzle inputs differ by user.</html>").is_err());
+    }
+}
@@ -0,0 +1,63 @@
+use std::panic::{self, AssertUnwindSafe};
+use common::Answer;
+use common::answers::Case;
+
+use crate::days;
+
+enum Outcome {
+    Answered(Answer),
+    Failed(String),
+}
+
+/// Runs every case, printing a pass/fail table. Returns whether all cases passed.
+pub fn verify(cases: &[Case]) -> bool {
+    println!("{:<4} {:<4} {:<10} {:>20} {:>20}  Result", "Day", "Part", "Input", "Expected", "Actual");
+
+    let mut failed = 0;
+    // Cases for the same input file are next to each other, parse each file only once
+    for group in cases.chunk_by(|a, b| a.day == b.day && a.input == b.input) {
+        let parts: Vec<u8> = group.iter().map(|case| case.part).collect();
+        let outcomes = run(group[0].day, &parts, &group[0].path());
+
+        for (case, outcome) in group.iter().zip(outcomes) {
+            let actual = match &outcome {
+                Outcome::Answered(answer) => answer.to_string(),
+                Outcome::Failed(_) => "-".to_string(),
+            };
+            let passed = matches!(&outcome, Outcome::Answered(answer) if *answer == case.expected);
+
+            println!("{:<4} {:<4} {:<10} {:>20} {:>20}  {}",
+                     format!("{:02}", case.day),
+                     case.part,
+                     case.input,
+                     case.expected.to_string(),
+                     actual,
+                     if passed { "pass" } else { "FAIL" });
+
+            if !passed {
+                failed += 1;
+                println!("     - expected: {}", case.expected);
+                match outcome {
+                    Outcome::Answered(answer) => println!("     + actual:   {}", answer),
+                    Outcome::Failed(reason) => println!("     + {}", reason),
+                }
+            }
+        }
+    }
+
+    println!();
+    println!("{} passed, {} failed", cases.len() - failed, failed);
+
+    failed == 0
+}
+
+/// Solves `parts`, turning parse errors and solver panics into failures.
+fn run(day: u8, parts: &[u8], path: &str) -> Vec<Outcome> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| days::solve(day, parts, path)));
+
+    match result {
+        Ok(Ok(reports)) => reports.into_iter().map(|report| Outcome::Answered(report.answer)).collect(),
+        Ok(Err(error)) => parts.iter().map(|_| Outcome::Failed(format!("error: {}", error))).collect(),
+        Err(_) => parts.iter().map(|_| Outcome::Failed("panicked".to_string())).collect(),
+    }
+}
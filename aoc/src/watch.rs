@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+use std::{env, fs, io, thread};
+
+use common::{answers, Error};
+use serde_json::Value;
+
+/// How often the files are looked at.
+const POLL: Duration = Duration::from_millis(500);
+
+/// The answer to one part as printed by a day's binary with `--format json`.
+#[derive(Debug, PartialEq)]
+pub struct PartReport {
+    pub part: u8,
+    /// The file name of the input, without its directory.
+    pub input: String,
+    pub answer: String,
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+}
+
+/// Rebuilds and reruns `day` on `inputs` from the day's input directory whenever one of the day's
+/// files changes, until interrupted.
+pub fn watch(day: u8, inputs: &[String]) -> Result<(), Error> {
+    let directory = PathBuf::from(format!("day-{:02}", day));
+    let mut seen = snapshot(&directory)?;
+    run(day, inputs);
+
+    loop {
+        thread::sleep(POLL);
+        let current = snapshot(&directory)?;
+        if current != seen {
+            seen = current;
+            run(day, inputs);
+        }
+    }
+}
+
+/// The modification time of every file under `directory`.
+fn snapshot(directory: &Path) -> Result<BTreeMap<PathBuf, SystemTime>, Error> {
+    let mut files = BTreeMap::new();
+    let mut pending = vec![directory.to_path_buf()];
+    while let Some(directory) = pending.pop() {
+        let entries = fs::read_dir(&directory)
+            .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", directory.display(), error)))?;
+        for entry in entries {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else {
+                files.insert(entry.path(), metadata.modified()?);
+            }
+        }
+    }
+    Ok(files)
+}
+
+fn run(day: u8, inputs: &[String]) {
+    if io::stdout().is_terminal() {
+        // Start from a clean screen, only the latest run matters
+        print!("\x1b[2J\x1b[H");
+    }
+    let package = format!("day-{:02}", day);
+    println!("Building {}", package);
+
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let built = Command::new(&cargo)
+        .args(["build", "--release", "--quiet", "-p", &package])
+        .status()
+        .is_ok_and(|status| status.success());
+    if !built {
+        println!("Build failed, waiting for changes");
+        return;
+    }
+
+    let paths: Vec<String> = inputs.iter().map(|input| format!("{}/input/{}", package, input)).collect();
+    let output = Command::new(&cargo)
+        .args(["run", "--release", "--quiet", "-p", &package, "--", "--format", "json", "--quiet"])
+        .args(&paths)
+        .stderr(Stdio::inherit())
+        .output();
+    let reports = match output {
+        Ok(output) if output.status.success() => read_reports(&String::from_utf8_lossy(&output.stdout)),
+        Ok(_) => Err(format!("{} failed", package)),
+        Err(error) => Err(error.to_string()),
+    };
+
+    match reports {
+        Ok(reports) => print_reports(day, &reports),
+        Err(message) => println!("{}, waiting for changes", message),
+    }
+}
+
+/// Reads what `--format json` printed, one report per line.
+pub fn read_reports(stdout: &str) -> Result<Vec<PartReport>, String> {
+    stdout.lines().map(|line| {
+        let report: Value = serde_json::from_str(line).map_err(|error| format!("{}: {}", error, line))?;
+        let field = |name: &str| report.get(name).ok_or_else(|| format!("no {} in {}", name, line));
+        let answer = match field("answer")? {
+            Value::String(text) => text.clone(),
+            Value::Null => "-".to_string(),
+            number => number.to_string(),
+        };
+        let input = field("input")?.as_str().unwrap_or_default();
+        Ok(PartReport {
+            part: field("part")?.as_u64().unwrap_or_default() as u8,
+            input: Path::new(input).file_name().map_or(input.into(), |name| name.to_string_lossy().into()),
+            answer,
+            parse_ms: field("parse_ms")?.as_f64().unwrap_or_default(),
+            solve_ms: field("solve_ms")?.as_f64().unwrap_or_default(),
+        })
+    }).collect()
+}
+
+fn print_reports(day: u8, reports: &[PartReport]) {
+    // Read the answers on every run, they may have been filled in while watching
+    let cases = match answers::load("answers.toml") {
+        Ok(cases) => cases,
+        Err(error) => {
+            println!("{}", error);
+            Vec::new()
+        }
+    };
+
+    println!("{:<4} {:<10} {:>20} {:>20}  {:<6} {:>10} {:>10}",
+             "Part", "Input", "Expected", "Actual", "Result", "Parse", "Solve");
+    for report in reports {
+        let expected = cases.iter()
+                            .find(|case| case.day == day && case.input == report.input && case.part == report.part)
+                            .map(|case| case.expected.to_string());
+        let result = match &expected {
+            Some(expected) if *expected == report.answer => "pass",
+            Some(_) => "FAIL",
+            None => "",
+        };
+        println!("{:<4} {:<10} {:>20} {:>20}  {:<6} {:>7.3} ms {:>7.3} ms",
+                 report.part,
+                 report.input,
+                 expected.as_deref().unwrap_or("-"),
+                 report.answer,
+                 result,
+                 report.parse_ms,
+                 report.solve_ms);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common::Answer;
+
+    use crate::stub::{report, scratch};
+
+    use super::*;
+
+    #[test]
+    fn notices_changed_and_new_files() {
+        let directory = scratch("watch");
+        fs::create_dir_all(directory.join("input")).unwrap();
+        fs::write(directory.join("input/test.dat"), "1\n").unwrap();
+        let before = snapshot(&directory).unwrap();
+        assert_eq!(snapshot(&directory).unwrap(), before);
+
+        let file = fs::File::options().write(true).open(directory.join("input/test.dat")).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1)).unwrap();
+        let touched = snapshot(&directory).unwrap();
+        assert_ne!(touched, before);
+
+        fs::write(directory.join("input/input.dat"), "2\n").unwrap();
+        assert_eq!(snapshot(&directory).unwrap().len(), touched.len() + 1);
+    }
+
+    #[test]
+    fn reads_the_reports_of_a_day() {
+        let report = |part, answer| report(3, part, "day-03/input/test.dat", answer);
+        let stdout = format!("{}\n{}\n", report(1, Answer::Number(357)).to_json(), report(2, Answer::Unsolved).to_json());
+
+        let reports = read_reports(&stdout).unwrap();
+        assert_eq!(reports[0], PartReport { part: 1, input: "test.dat".to_string(), answer: "357".to_string(), parse_ms: 1.5, solve_ms: 0.25 });
+        assert_eq!(reports[1].answer, "-");
+        assert!(read_reports("Part 1: 357\n").is_err());
+    }
+}
@@ -0,0 +1,16 @@
+[package]
+name = "day-{{nn}}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+common = { path = "../common" }
+tracing = "0.1"
+
+[dev-dependencies]
+common = { path = "../common", features = ["bench"] }
+criterion = "0.5"
+
+[[bench]]
+name = "day_{{nn}}"
+harness = false
@@ -0,0 +1,15 @@
+use std::path::Path;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_{{nn}}::Day{{nn}};
+
+fn bench(c: &mut Criterion) {
+    common::bench::<Day{{nn}}>(c, "input/test.dat", &[1, 2]);
+    // A new day has no puzzle input until `aoc fetch` downloads it
+    if Path::new("input/input.dat").exists() {
+        common::bench::<Day{{nn}}>(c, "input/input.dat", &[1, 2]);
+    }
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+use tracing::trace;
+use common::{Answer, ParseError, Solution};
+
+pub struct Day{{nn}};
+
+impl Solution for Day{{nn}} {
+    const DAY: u8 = {{day}};
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve2(input)
+    }
+}
+
+pub fn solve1(lines: &[String]) -> Answer {
+    trace!(lines = lines.len(), "solving part 1");
+    Answer::Unsolved
+}
+
+pub fn solve2(lines: &[String]) -> Answer {
+    trace!(lines = lines.len(), "solving part 2");
+    Answer::Unsolved
+}
+
+/// One line of the puzzle per line of the input.
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(str::to_string).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The expected answers are in answers.toml.
+    #[test]
+    fn example() {
+        common::answers::check::<Day{{nn}}>("test.dat");
+    }
+}
@@ -0,0 +1,6 @@
+use common::Error;
+use day_{{nn}}::Day{{nn}};
+
+fn main() -> Result<(), Error> {
+    common::main::<Day{{nn}}>()
+}
@@ -0,0 +1,74 @@
+use std::net::{TcpListener, TcpStream};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+/// `aoc serve` on a free port, stopped when dropped.
+struct Server {
+    child: Child,
+    url: String,
+}
+
+impl Server {
+    fn start() -> Server {
+        let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--port", &port.to_string()])
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap();
+        for _ in 0..100 {
+            if TcpStream::connect(("127.0.0.1", port)).is_ok() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        Server { child, url: format!("http://127.0.0.1:{}", port) }
+    }
+
+    fn post(&self, path: &str, body: &str) -> (u16, Value) {
+        let (status, response) = match ureq::post(&format!("{}{}", self.url, path)).send_string(body) {
+            Ok(response) => (response.status(), response),
+            Err(ureq::Error::Status(status, response)) => (status, response),
+            Err(error) => panic!("{}: {}", path, error),
+        };
+        assert_eq!(response.content_type(), "application/json");
+        (status, serde_json::from_str(&response.into_string().unwrap()).unwrap())
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn answers_over_http() {
+    let server = Server::start();
+    let example = include_str!("../../day-01/input/test.dat");
+
+    let (status, value) = server.post("/day/1/part/2", example);
+    assert_eq!(status, 200);
+    assert_eq!((&value["day"], &value["part"], &value["answer"]), (&json!(1), &json!(2), &json!(6)));
+    assert!(value["parse_ms"].is_f64() && value["solve_ms"].is_f64());
+
+    let (status, value) = server.post("/day/1/part/1", "L68\nX12\n");
+    assert_eq!((status, &value["line"], &value["column"]), (422, &json!(2), &json!(1)));
+}
+
+#[test]
+fn a_crashing_solver_leaves_the_server_running() {
+    let server = Server::start();
+
+    // A loop in the graph sends the day 11 search around it until the stack overflows
+    let (status, value) = server.post("/day/11/part/1", "you: aaa\naaa: you\n");
+    assert_eq!(status, 500);
+    assert!(value["error"].as_str().unwrap().starts_with("day 11 part 1 crashed"), "{}", value);
+
+    let (status, value) = server.post("/day/11/part/1", include_str!("../../day-11/input/test.dat"));
+    assert_eq!((status, &value["answer"]), (200, &json!(5)));
+}
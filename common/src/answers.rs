@@ -0,0 +1,82 @@
+use std::{fs, io};
+
+use toml::{Table, Value};
+
+use crate::{Answer, Error, Solution};
+
+/// The answers file at the root of the workspace.
+pub const FILENAME: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// One expected answer from the answers file.
+pub struct Case {
+    pub day: u8,
+    pub input: String,
+    pub part: u8,
+    pub expected: Answer,
+}
+
+impl Case {
+    pub fn path(&self) -> String {
+        format!("day-{:02}/input/{}", self.day, self.input)
+    }
+}
+
+/// Reads the expected answers in the order they are listed.
+pub fn load(filename: &str) -> Result<Vec<Case>, Error> {
+    let text = fs::read_to_string(filename)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", filename, error)))?;
+    let table: Table = text.parse().map_err(|error| invalid(filename, error))?;
+
+    let mut cases = Vec::new();
+    for (key, inputs) in &table {
+        let day = key.strip_prefix("day-")
+                     .and_then(|day| day.parse::<u8>().ok())
+                     .filter(|day| (1..=25).contains(day))
+                     .ok_or_else(|| invalid(filename, format!("expected a day-NN table, found '{}'", key)))?;
+        let inputs = inputs.as_table()
+                           .ok_or_else(|| invalid(filename, format!("[{}] is not a table", key)))?;
+
+        for (input, parts) in inputs {
+            let parts = parts.as_table()
+                             .ok_or_else(|| invalid(filename, format!("{}.{} is not a table", key, input)))?;
+
+            for (part_key, value) in parts {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => return Err(invalid(filename, format!("expected part1 or part2, found '{}'", part_key))),
+                };
+                let expected = match value {
+                    Value::Integer(number) => Answer::Number(*number as i128),
+                    Value::String(text) => Answer::Text(text.clone()),
+                    _ => return Err(invalid(filename, format!("{}.{}.{} is not a number or string", key, input, part_key))),
+                };
+                cases.push(Case { day, input: input.clone(), part, expected });
+            }
+        }
+    }
+
+    Ok(cases)
+}
+
+/// Checks the answers of `S` for `input` in the day's input directory against the answers file.
+/// Meant for the tests of a day, which run in the day's directory. Panics when the answers file
+/// has no answer for `input`, a check of nothing would pass for the wrong reason.
+pub fn check<S: Solution>(input: &str) {
+    let filename = format!("input/{}", input);
+    let text = fs::read_to_string(&filename).unwrap_or_else(|error| panic!("{}: {}", filename, error));
+    let parsed = S::parse(&text).unwrap_or_else(|error| panic!("{}: {}", filename, error));
+
+    let cases: Vec<Case> = load(FILENAME).unwrap()
+                                         .into_iter()
+                                         .filter(|case| case.day == S::DAY && case.input == input)
+                                         .collect();
+    assert!(!cases.is_empty(), "{} has no answers for day {} on {}", FILENAME, S::DAY, input);
+    for case in cases {
+        assert_eq!(S::solve(&parsed, case.part), case.expected, "day {} part {} of {}", S::DAY, case.part, input);
+    }
+}
+
+fn invalid(filename: &str, message: impl std::fmt::Display) -> Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", filename, message)).into()
+}
@@ -0,0 +1,26 @@
+use std::fs;
+use std::hint::black_box;
+
+use criterion::Criterion;
+
+use crate::{progress, Solution};
+
+/// Benchmarks parsing `filename` and answering `parts` from it, named after the day and file.
+pub fn bench<S: Solution>(c: &mut Criterion, filename: &str, parts: &[u8]) {
+    // Redrawing progress bars would only add noise to the measurements
+    progress::set_quiet(true);
+    let text = fs::read_to_string(filename).unwrap_or_else(|error| panic!("{}: {}", filename, error));
+    let input = S::parse(&text).unwrap_or_else(|error| panic!("{}", error.in_file(filename)));
+    let name = filename.rsplit('/').next().unwrap_or(filename);
+
+    let mut group = c.benchmark_group(format!("day-{:02}/{}", S::DAY, name));
+    // Some parts take seconds, the minimum number of samples keeps a run bearable
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&text))));
+    for &part in parts {
+        group.bench_function(format!("part {}", part), |b| b.iter(|| S::solve(black_box(&input), part)));
+    }
+
+    group.finish();
+}
@@ -0,0 +1,188 @@
+pub mod answers;
+#[cfg(feature = "bench")]
+mod bench;
+mod error;
+pub mod memory;
+pub mod progress;
+mod report;
+mod scanner;
+mod solution;
+
+#[cfg(feature = "bench")]
+pub use bench::bench;
+pub use error::{Error, ParseError};
+pub use report::{print_memory, print_times, Format, Report};
+pub use scanner::Scanner;
+pub use solution::{Answer, Solution};
+
+use std::{env, io};
+use std::fs::File;
+use std::io::{BufRead, BufReader, IsTerminal};
+use std::time::{Duration, Instant};
+
+use tracing_subscriber::EnvFilter;
+
+use crate::memory::Memory;
+
+/// The file name that stands for standard input.
+pub const STDIN: &str = "-";
+
+/// Reads everything from `reader` and hands it to `parse`, tagging errors with `name`.
+pub fn parse_reader<T>(name: &str,
+                       mut reader: impl BufRead,
+                       parse: impl FnOnce(&str) -> Result<T, ParseError>) -> Result<T, Error> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)
+          .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", name, error)))?;
+    parse(&text).map_err(|error| error.in_file(name).into())
+}
+
+/// Reads `filename`, or standard input for `-`, and hands its contents to `parse`.
+pub fn parse_file<T>(filename: &str,
+                     parse: impl FnOnce(&str) -> Result<T, ParseError>) -> Result<T, Error> {
+    if filename == STDIN {
+        return parse_reader("<stdin>", io::stdin().lock(), parse);
+    }
+    let file = File::open(filename)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", filename, error)))?;
+    parse_reader(filename, BufReader::new(file), parse)
+}
+
+/// Sends the solvers' events to stderr. Silent by default, `verbosity` 1 shows the debug events
+/// and 2 every step. A `RUST_LOG` filter takes precedence over the verbosity.
+pub fn init_tracing(verbosity: u8) {
+    let level = match verbosity {
+        0 => "off",
+        1 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+    // A subscriber that is already set, e.g. by an earlier call, stays
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .try_init();
+}
+
+/// Answers `parts` for the puzzle input in `filename`, timing the parse and every part, and
+/// counting what they allocate when `memory::enable` was called.
+pub fn run<S: Solution>(parts: &[u8], filename: &str) -> Result<Vec<Report>, Error> {
+    let start = Instant::now();
+    let (input, parse_memory) = memory::measure(|| parse_file(filename, S::parse));
+    let input = input?;
+    Ok(answer::<S>(parts, filename, &input, start.elapsed(), parse_memory))
+}
+
+/// Like `run` for a puzzle input that is already in memory, `name` standing in for its file.
+pub fn run_text<S: Solution>(parts: &[u8], name: &str, text: &str) -> Result<Vec<Report>, ParseError> {
+    let start = Instant::now();
+    let (input, parse_memory) = memory::measure(|| S::parse(text));
+    let input = input?;
+    Ok(answer::<S>(parts, name, &input, start.elapsed(), parse_memory))
+}
+
+fn answer<S: Solution>(parts: &[u8],
+                       name: &str,
+                       input: &S::Input,
+                       parse_time: Duration,
+                       parse_memory: Option<Memory>) -> Vec<Report> {
+    parts.iter().map(|&part| {
+        let start = Instant::now();
+        let (answer, solve_memory) = memory::measure(|| S::solve(input, part));
+        Report {
+            day: S::DAY,
+            part,
+            input: name.to_string(),
+            answer,
+            parse_time,
+            solve_time: start.elapsed(),
+            parse_memory,
+            solve_memory,
+        }
+    }).collect()
+}
+
+/// The `main` of every day binary: answers both parts for every file named on the command line,
+/// `-` being standard input, optionally followed by `--format json`, `--time`, `--quiet` and
+/// `-v` or `-vv`.
+pub fn main<S: Solution>() -> Result<(), Error> {
+    let mut args = env::args();
+    let program = args.next().unwrap_or_default();
+    let usage = || invalid_argument(format!("usage: {} <input>... [--format text|json] [--time] [--quiet] [-v|-vv]", program));
+
+    let mut filenames = Vec::new();
+    let mut format = Format::Text;
+    let mut time = false;
+    let mut verbosity = 0;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = args.next().ok_or_else(usage)?.parse().map_err(invalid_argument)?,
+            "--time" => time = true,
+            "--quiet" => progress::set_quiet(true),
+            "-v" => verbosity = 1,
+            "-vv" => verbosity = 2,
+            _ if arg == STDIN || !arg.starts_with('-') => filenames.push(arg),
+            _ => return Err(usage()),
+        }
+    }
+    if filenames.is_empty() {
+        return Err(usage());
+    }
+    init_tracing(verbosity);
+
+    for filename in &filenames {
+        let reports = run::<S>(&[1, 2], filename)?;
+        if filenames.len() > 1 && format == Format::Text {
+            println!("{}:", filename);
+        }
+        for report in &reports {
+            match format {
+                Format::Text => println!("Part {}: {}", report.part, report.answer),
+                Format::Json => println!("{}", report.to_json()),
+            }
+        }
+        // The JSON output always carries the times
+        if time && format == Format::Text {
+            print_times(&reports);
+        }
+    }
+
+    Ok(())
+}
+
+fn invalid_argument(message: String) -> Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(input: &str) -> Result<Vec<u32>, ParseError> {
+        input.lines().enumerate().map(|(index, line)| {
+            let mut scanner = Scanner::new(line, index + 1);
+            let number = scanner.unsigned()?;
+            scanner.expect_end()?;
+            Ok(number)
+        }).collect()
+    }
+
+    #[test]
+    fn parses_from_any_reader() {
+        let input = parse_reader("numbers", "1\n2\n3\n".as_bytes(), numbers).unwrap();
+        assert_eq!(input, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn reader_errors_name_the_input() {
+        let error = parse_reader("numbers", "1\nx\n".as_bytes(), numbers).unwrap_err();
+        assert_eq!(error.to_string(), "numbers:2:1: expected a number, found 'x'");
+    }
+
+    #[test]
+    fn tracing_can_be_set_up_twice() {
+        init_tracing(0);
+        init_tracing(2);
+    }
+}
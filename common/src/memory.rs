@@ -0,0 +1,177 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicU64, Ordering};
+
+use serde::Serialize;
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+/// Bytes in use since counting started, below zero after freeing what was allocated before.
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// The system allocator, counting what it hands out once `enable` is called. A binary opts in with
+/// `#[global_allocator] static ALLOCATOR: Counting = Counting;`.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        freed(layout.size());
+    }
+
+    /// Counts as a new allocation of `new_size` bytes that frees the old one.
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(pointer, layout, new_size);
+        if !new.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+fn allocated(size: usize) {
+    if COUNTING.load(Ordering::Relaxed) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+fn freed(size: usize) {
+    if COUNTING.load(Ordering::Relaxed) {
+        LIVE.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+/// Starts counting allocations, for `--mem`. Without `Counting` as the global allocator every
+/// count stays zero.
+pub fn enable() {
+    COUNTING.store(true, Ordering::Relaxed);
+}
+
+/// What one phase of a run allocated. The counts are process wide, so they include every thread
+/// the phase started and anything else running at the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Memory {
+    pub allocations: u64,
+    /// Bytes allocated in total, however soon they were freed again.
+    pub bytes: u64,
+    /// The most heap bytes in use at once on top of what was in use when the phase began.
+    pub peak_bytes: u64,
+    /// The most memory the whole process had resident during the phase, stacks and binary
+    /// included. Only Linux lets the high-water mark start over for a phase, elsewhere it is `None`.
+    pub peak_resident_bytes: Option<u64>,
+}
+
+/// Runs `phase`, counting what it allocates when counting is enabled.
+pub fn measure<T>(phase: impl FnOnce() -> T) -> (T, Option<Memory>) {
+    if !COUNTING.load(Ordering::Relaxed) {
+        return (phase(), None);
+    }
+
+    let resident = reset_resident_peak();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let value = phase();
+    let mut memory = Memory {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak_bytes: (PEAK.load(Ordering::Relaxed) - live).max(0) as u64,
+        peak_resident_bytes: None,
+    };
+    // Reading the high-water mark allocates, so only once the counts are taken
+    if resident {
+        memory.peak_resident_bytes = resident_peak();
+    }
+    (value, Some(memory))
+}
+
+/// Starts the process's resident high-water mark over from what is resident now. Returns whether
+/// it did.
+fn reset_resident_peak() -> bool {
+    fs::write("/proc/self/clear_refs", "5").is_ok()
+}
+
+/// `VmHWM` of the process, the most it has had resident since the last reset.
+fn resident_peak() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find_map(|line| line.strip_prefix("VmHWM:"))?;
+    let kibibytes: u64 = line.trim().strip_suffix("kB")?.trim().parse().ok()?;
+    Some(kibibytes * 1024)
+}
+
+/// `bytes` in the largest binary unit that keeps it above one, e.g. `1.5 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn counts_what_a_phase_allocates() {
+        enable();
+        let (sum, memory) = measure(|| {
+            let big = black_box(vec![1u8; 1 << 20]);
+            let small: Vec<Box<u64>> = (0..100).map(|number| Box::new(black_box(number))).collect();
+            big.len() as u64 + small.iter().map(|number| **number).sum::<u64>()
+        });
+        assert_eq!(sum, (1 << 20) + 4950);
+
+        // Other tests allocate on other threads at the same time, so these are lower bounds
+        let memory = memory.unwrap();
+        assert!(memory.allocations >= 102, "{:?}", memory);
+        assert!(memory.bytes >= (1 << 20) + 800, "{:?}", memory);
+        assert!(memory.peak_bytes >= 1 << 20, "{:?}", memory);
+        if cfg!(target_os = "linux") {
+            assert!(memory.peak_resident_bytes.is_some_and(|bytes| bytes >= 1 << 20), "{:?}", memory);
+        }
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+    }
+}
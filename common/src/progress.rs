@@ -0,0 +1,45 @@
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use indicatif::{ParallelProgressIterator, ProgressBar, ProgressBarIter, ProgressFinish, ProgressIterator,
+                ProgressStyle};
+use rayon::iter::IndexedParallelIterator;
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Hides every progress bar from now on, for `--quiet`.
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// A progress bar on stderr for `len` items, showing the items per second and the time left.
+/// It stays hidden with `--quiet` or when stderr is not a terminal.
+pub fn bar(len: u64) -> ProgressBar {
+    if QUIET.load(Ordering::Relaxed) || !io::stderr().is_terminal() {
+        return ProgressBar::hidden();
+    }
+
+    let style = ProgressStyle::with_template("{bar:40} {pos}/{len} {per_sec} ETA {eta}")
+        .expect("the template is valid");
+    ProgressBar::new(len).with_style(style).with_finish(ProgressFinish::AndClear)
+}
+
+/// Shows the progress of an iterator that knows its length.
+pub trait Progress: ExactSizeIterator + Sized {
+    fn progress(self) -> ProgressBarIter<Self> {
+        let bar = bar(self.len() as u64);
+        self.progress_with(bar)
+    }
+}
+
+impl<I: ExactSizeIterator> Progress for I {}
+
+/// Shows the progress of a rayon iterator, counting the items as the threads finish them.
+pub trait ParallelProgress: IndexedParallelIterator {
+    fn progress(self) -> ProgressBarIter<Self> {
+        let bar = bar(self.len() as u64);
+        self.progress_with(bar)
+    }
+}
+
+impl<I: IndexedParallelIterator> ParallelProgress for I {}
@@ -0,0 +1,89 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+use crate::memory::{self, Memory};
+use crate::Answer;
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// One human readable line per part.
+    #[default]
+    Text,
+    /// One JSON object per part and line (NDJSON).
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("expected text or json, found '{}'", s)),
+        }
+    }
+}
+
+/// The answer to one part together with how long it took.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Answer,
+    /// Time spent reading the input, shared by all parts answered from it.
+    #[serde(rename = "parse_ms", serialize_with = "milliseconds")]
+    pub parse_time: Duration,
+    #[serde(rename = "solve_ms", serialize_with = "milliseconds")]
+    pub solve_time: Duration,
+    /// What reading the input allocated, when counting allocations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_memory: Option<Memory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_memory: Option<Memory>,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a report always serializes")
+    }
+}
+
+/// Prints how long reading the input and each part took, for `--time`.
+pub fn print_times(reports: &[Report]) {
+    if let Some(first) = reports.first() {
+        println!("Parse:  {:>10.1?}", first.parse_time);
+    }
+    for report in reports {
+        println!("Part {}: {:>10.1?}", report.part, report.solve_time);
+    }
+}
+
+/// Prints what reading the input and each part allocated, for `--mem`.
+pub fn print_memory(reports: &[Report]) {
+    let print = |phase: &str, memory: Option<Memory>| {
+        if let Some(memory) = memory {
+            println!("{:<7} {:>10} allocations {:>12} allocated {:>12} peak heap {:>12} peak resident",
+                     phase,
+                     memory.allocations,
+                     memory::format_bytes(memory.bytes),
+                     memory::format_bytes(memory.peak_bytes),
+                     memory.peak_resident_bytes.map_or("-".to_string(), memory::format_bytes));
+        }
+    };
+    if let Some(first) = reports.first() {
+        print("Parse:", first.parse_memory);
+    }
+    for report in reports {
+        print(&format!("Part {}:", report.part), report.solve_memory);
+    }
+}
+
+fn milliseconds<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    // Microsecond precision is plenty and keeps the numbers short
+    serializer.serialize_f64(duration.as_micros() as f64 / 1000.0)
+}
@@ -0,0 +1,143 @@
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Walks over a single input line, producing `ParseError`s that point at the current column.
+pub struct Scanner<'a> {
+    line: &'a str,
+    line_number: usize,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    /// `line_number` is 1-based, as it is shown to the user.
+    pub fn new(line: &'a str, line_number: usize) -> Self {
+        Scanner { line, line_number, position: 0 }
+    }
+
+    pub fn column(&self) -> usize {
+        self.line[..self.position].chars().count() + 1
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.position..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position == self.line.len()
+    }
+
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    /// Consumes `c` when it is next, reporting whether it was.
+    pub fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn eat_str(&mut self, s: &str) -> bool {
+        if self.rest().starts_with(s) {
+            self.position += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(format!("'{}'", c)))
+        }
+    }
+
+    pub fn expect_str(&mut self, s: &str) -> Result<(), ParseError> {
+        if self.eat_str(s) {
+            Ok(())
+        } else {
+            Err(self.error(format!("'{}'", s)))
+        }
+    }
+
+    pub fn expect_end(&self) -> Result<(), ParseError> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Parses an optionally negative run of digits.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.parse_digits(true)
+    }
+
+    /// Parses a run of digits without a sign.
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.parse_digits(false)
+    }
+
+    fn parse_digits<T: FromStr>(&mut self, signed: bool) -> Result<T, ParseError> {
+        let start = self.position;
+        if signed {
+            self.eat('-');
+        }
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            self.position = start;
+            return Err(self.error("a number"));
+        }
+        self.line[start..self.position].parse::<T>().map_err(|_| {
+            let found = self.line[start..self.position].to_string();
+            self.position = start;
+            self.error_found("a number that fits", format!("'{}'", found))
+        })
+    }
+
+    /// A word is a run of characters up to the next whitespace.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        let word = self.take_while(|c| !c.is_whitespace());
+        if word.is_empty() {
+            Err(self.error("a word"))
+        } else {
+            Ok(word)
+        }
+    }
+
+    /// An error at the current column, showing the next character as what was found.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = match self.peek() {
+            Some(c) => format!("'{}'", c),
+            None => "end of line".to_string(),
+        };
+        self.error_found(expected, found)
+    }
+
+    pub fn error_found(&self, expected: impl Into<String>, found: impl Into<String>) -> ParseError {
+        ParseError::new(self.line_number, self.column(), expected, found)
+    }
+}
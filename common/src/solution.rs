@@ -0,0 +1,82 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use crate::ParseError;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The part has no answer, e.g. the second part of the last day.
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+/// Numbers become JSON numbers where they fit, otherwise strings, and unsolved parts `null`.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(number) => match i64::try_from(*number) {
+                Ok(number) => serializer.serialize_i64(number),
+                Err(_) => serializer.serialize_str(&number.to_string()),
+            },
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    // Only a u128 can be too large, which still prints fine as text
+                    i128::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Number)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, i64, u32, u64, usize, u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// A day's puzzle: how to read its input and how to answer both parts.
+pub trait Solution {
+    /// The day of December the puzzle was released on.
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Answers `part`, which is either 1 or 2.
+    fn solve(input: &Self::Input, part: u8) -> Answer {
+        match part {
+            1 => Self::part1(input),
+            2 => Self::part2(input),
+            _ => panic!("there is no part {}", part),
+        }
+    }
+}
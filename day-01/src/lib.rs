@@ -0,0 +1,130 @@
+use tracing::trace;
+use common::{Answer, ParseError, Scanner, Solution};
+
+/// A single rotation of the dial, `direction` is -1 for left and 1 for right.
+pub struct Instruction {
+    direction: i32,
+    amount: i32,
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve2(input).into()
+    }
+}
+
+pub fn solve(input: &[Instruction]) -> i32 {
+    let min = 0;
+    let max = 99;
+
+    let mut password = 0;
+    let mut dial = 50;
+    for instruction in input {
+        let direction = instruction.direction;
+        let raw_amount = &instruction.amount;
+        let amount = (raw_amount % 100) * direction;
+        dial += amount;
+        if dial < min {
+            dial += 100;
+        } else if dial > max {
+            dial = min + (dial - max)  - 1;
+        }
+        if dial == 0 {
+            password += 1;
+        }
+        trace!(amount, dial, "dial turned");
+    }
+
+    password
+}
+
+pub fn solve2(input: &[Instruction]) -> i32 {
+    let min = 0;
+    let max = 99;
+
+    let mut password = 0;
+    let mut dial = 50;
+    for instruction in input {
+        let direction = instruction.direction;
+        let raw_amount = &instruction.amount;
+
+        if raw_amount == &0 { continue }
+
+        let amount = (raw_amount % 100) * direction;
+        let mut rotations = raw_amount / 100;
+        let org_dial = dial;
+
+        let mut already_hit = false;
+
+        dial += amount;
+        if dial < min {
+            if org_dial != 0 {
+                rotations += 1;
+            }
+            dial += 100;
+        } else if dial > max {
+            rotations += 1;
+            already_hit = true;
+            dial = min + (dial - max)  - 1;
+        }
+
+        if dial == 0 && !already_hit {
+            rotations += 1;
+        }
+
+        password += rotations;
+
+        trace!(amount, rotations, dial, "dial turned");
+    }
+
+    password
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input.lines().enumerate().map(|(index, line)| {
+        let mut scanner = Scanner::new(line, index + 1);
+        let direction = if scanner.eat('L') {
+            -1
+        } else if scanner.eat('R') {
+            1
+        } else {
+            return Err(scanner.error("'L' or 'R'"));
+        };
+        let amount = scanner.unsigned::<i32>()?;
+        scanner.expect_end()?;
+        Ok(Instruction { direction, amount })
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&input), 3);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve2(&input), 6);
+    }
+}
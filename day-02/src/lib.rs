@@ -0,0 +1,154 @@
+use tracing::trace;
+use common::{Answer, ParseError, Scanner, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(i64, i64)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve2(input).into()
+    }
+}
+
+pub fn solve2(input: &[(i64, i64)]) -> i64 {
+    input.iter().map(|x| { handle_range2(x).iter().sum::<i64>() } ).sum::<i64>()
+}
+
+
+pub fn solve(input: &[(i64, i64)]) -> i64 {
+    input.iter().map(|x| { handle_range(x).iter().sum::<i64>() } ).sum::<i64>()
+}
+
+fn handle_range2(range: &(i64, i64)) -> Vec<i64> {
+    let mut rvalue = Vec::new();
+
+    for id in range.0..range.1+1 {
+        if is_invalid2(id) {
+            rvalue.push(id)
+        }
+    }
+
+    trace!(lower = range.0, upper = range.1, invalid = rvalue.len(), "range checked");
+    rvalue
+}
+
+fn handle_range(range: &(i64, i64)) -> Vec<i64> {
+    let mut rvalue = Vec::new();
+
+    for id in range.0..range.1+1 {
+        if is_invalid(id) {
+            rvalue.push(id)
+        }
+    }
+    trace!(lower = range.0, upper = range.1, invalid = rvalue.len(), "range checked");
+    rvalue
+}
+
+fn is_invalid(id: i64) -> bool {
+    let s = id.to_string();
+    if s.len() % 2 == 1 {
+        return false
+    }
+
+    let window_size = s.len() / 2;
+
+    let s1 = &s[0..window_size];
+    let s2 = &s[window_size..];
+
+    s1 == s2
+}
+
+fn is_invalid2(id: i64) -> bool {
+    let s = id.to_string();
+
+    let max_window_size = s.len() / 2;
+
+    for window_size in 1..=max_window_size {
+        if !s.len().is_multiple_of(window_size) { continue }
+        let n = s.len() / window_size;
+        let chunks: Vec<&str> = (0..n)
+            .map(|i| {
+                let start = i * window_size;
+                let end = start + window_size;
+                &s[start..end]
+            })
+            .collect();
+
+        let first = chunks.first().unwrap();
+
+        if chunks.iter().all(|c| c == first) {
+            return true;
+        }
+    }
+    false
+}
+
+/// The ranges all sit on the first line, as `lower-upper` separated by commas.
+pub fn parse(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let line = input.lines().next()
+                    .ok_or_else(|| ParseError::end_of_input(1, "an ID range"))?;
+    let mut scanner = Scanner::new(line, 1);
+    let mut rvalue = Vec::new();
+
+    loop {
+        let lower = scanner.unsigned::<i64>()?;
+        scanner.expect('-')?;
+        let upper = scanner.unsigned::<i64>()?;
+        rvalue.push((lower, upper));
+
+        if !scanner.eat(',') {
+            break;
+        }
+    }
+    scanner.expect_end()?;
+
+    Ok(rvalue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&input), 1227775554);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve2(&input), 4174379265);
+    }
+
+    #[test]
+    fn repeated_twice_is_invalid() {
+        assert!(is_invalid(55));
+        assert!(is_invalid(123123));
+        assert!(!is_invalid(101));
+        assert!(!is_invalid(111));
+    }
+
+    #[test]
+    fn repeated_at_least_twice_is_invalid() {
+        assert!(is_invalid2(111));
+        assert!(is_invalid2(121212));
+        assert!(is_invalid2(1188511885));
+        assert!(!is_invalid2(7));
+        assert!(!is_invalid2(1231));
+        assert!(!is_invalid2(12121));
+    }
+}
@@ -0,0 +1,10 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_03::Day03;
+
+fn bench(c: &mut Criterion) {
+    common::bench::<Day03>(c, "input/test.dat", &[1, 2]);
+    common::bench::<Day03>(c, "input/input.dat", &[1, 2]);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,257 @@
+use std::cmp::{max, Ordering};
+use std::collections::BinaryHeap;
+use tracing::{debug, trace};
+use common::{Answer, ParseError, Scanner, Solution};
+use common::progress::Progress;
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve2(input).into()
+    }
+}
+
+pub fn solve1(input: &[Vec<u64>]) -> u64 {
+    input.iter().map(|x|find_max_voltage_for_bank(x)).sum::<u64>()
+}
+
+pub fn solve2(input: &[Vec<u64>]) -> u64 {
+    input.iter()
+         .progress()
+         .map(|x| find_max_voltage_for_bank_with_12_lamps(x))
+         .sum::<u64>()
+}
+
+fn find_max_voltage_for_bank_with_12_lamps(bank: &[u64]) -> u64 {
+    let mut heap: BinaryHeap<SelectionState> = BinaryHeap::new();
+
+    let mut lower_bound: u64 = 0;
+
+    // Start with an empty selection
+    heap.push(SelectionState {
+        current_index: 0,
+        selected_indices: Vec::new(),
+        bank,
+    });
+
+    while let Some(state) = heap.pop() {
+
+        if state.current_upper_bound() < lower_bound {
+            continue;
+        }
+
+        if state.full() {
+            if state.current_value() > lower_bound {
+                trace!(joltage = state.current_value(), "better selection found");
+            }
+            lower_bound = max(lower_bound, state.current_value());
+            continue
+        }
+
+        // Decision to take next digit
+        let next_state = state.take_current_digit();
+        if next_state.can_complete() && next_state.current_upper_bound() > lower_bound {
+            heap.push(next_state)
+        }
+
+        // Decision to not take next digit
+        let next_state = state.skip_current_digit();
+        if next_state.can_complete() && next_state.current_upper_bound() > lower_bound {
+            heap.push(next_state)
+        }
+
+    }
+
+    debug!(joltage = lower_bound, "bank done");
+    lower_bound
+}
+
+struct SelectionState<'a> {
+    current_index: usize,
+    selected_indices: Vec<usize>,
+    bank: &'a [u64],
+}
+
+impl<'a> SelectionState<'a> {
+
+    fn full(&self) -> bool {
+        self.selected_indices.len() == 12
+    }
+
+    fn can_complete(&self) -> bool {
+        let remaining_digits = self.bank.len() - self.current_index;
+        remaining_digits + self.selected_indices.len() >= 12
+    }
+
+    fn current_value(&self) -> u64 {
+        let mut radix: i32 = 11;
+        let mut value: u64 = 0;
+        for selected_index in self.selected_indices.iter() {
+            value += self.bank.get(*selected_index).unwrap()
+                        * 10u64.pow(radix as u32);
+            radix -= 1;
+        }
+        value
+    }
+
+    fn current_upper_bound(&self) -> u64 {
+        let mut radix: i32 = 11;
+        let mut value: u64 = 0;
+        for selected_index in self.selected_indices.iter() {
+            value += self.bank.get(*selected_index).unwrap()
+                * 10u64.pow(radix as u32);
+            radix -= 1;
+        }
+
+        // TODO Highest remaining digits
+        // find the remaining (radix + 1) digits from bank, with an index higher than or equal to
+        // current_index and add them sorted lowest to highest in a tmp Vec<u64> name best_candidates
+
+        if radix < 0 {
+            return value;
+        }
+
+        // How many digits still need to be chosen
+        let slots_left = (radix + 1) as usize;
+
+        // Highest remaining digits:
+        // find the remaining digits from bank, with an index >= current_index
+        let mut remaining: Vec<u64> = self
+            .bank
+            .iter()
+            .enumerate()
+            .skip(self.current_index)
+            .map(|(_, &v)| v)
+            .collect();
+
+        // sort ascending
+        remaining.sort_unstable();
+
+        // Take the largest `slots_left` digits.
+        // They will still be in ascending order (lowest -> highest) in best_candidates.
+        let len = remaining.len();
+        let start = len - slots_left;
+        let best_candidates: Vec<u64> = remaining[start..].to_vec();
+
+        while radix >= 0 {
+            value += best_candidates.get(radix as usize).unwrap()
+                * 10u64.pow(radix as u32);
+            radix -= 1;
+        }
+
+        value
+    }
+
+    fn take_current_digit(&self) -> Self {
+        let mut next_selected_indices = self.selected_indices.clone();
+        next_selected_indices.push(self.current_index);
+        SelectionState {
+            current_index: self.current_index + 1,
+            selected_indices: next_selected_indices,
+            bank: self.bank,
+        }
+    }
+
+    fn skip_current_digit(&self) -> Self {
+        SelectionState {
+            current_index: self.current_index + 1,
+            selected_indices: self.selected_indices.clone(),
+            bank: self.bank,
+        }
+    }
+}
+
+impl<'a> Eq for SelectionState<'a> {}
+
+impl<'a> PartialEq for SelectionState<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.current_upper_bound() == other.current_upper_bound()
+    }
+}
+
+impl<'a> Ord for SelectionState<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.current_upper_bound().cmp(&other.current_upper_bound())
+    }
+}
+
+impl<'a> PartialOrd for SelectionState<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn find_max_voltage_for_bank(bank: &[u64]) -> u64 {
+    index_pairs(bank).map(|(i,j)| {
+        bank.get(j).unwrap() * 10 +
+        bank.get(i).unwrap()
+    }).max().unwrap()
+}
+
+fn index_pairs(bank: &[u64]) -> impl Iterator<Item = (usize, usize)> {
+    let n = bank.len();
+
+    (1..n).flat_map(move |i| {
+        (0..i).map(move |j| (i, j))
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    input.lines().enumerate().map(|(index, line)| {
+        let mut scanner = Scanner::new(line, index + 1);
+        let mut bank = Vec::new();
+        while let Some(digit) = scanner.peek().and_then(|c| c.to_digit(10)) {
+            scanner.next_char();
+            bank.push(digit as u64);
+        }
+        // The second part turns on twelve batteries per bank
+        if bank.len() < 12 {
+            return Err(scanner.error("12 digits"));
+        }
+        scanner.expect_end()?;
+        Ok(bank)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve1(&input), 357);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve2(&input), 3121910778619);
+    }
+
+    #[test]
+    fn rejects_banks_of_fewer_than_12_batteries() {
+        let error = parse("987654321111111
+12345
+").unwrap_err();
+        assert_eq!(error.to_string(), "2:6: expected 12 digits, found end of line");
+    }
+}
@@ -0,0 +1,17 @@
+//! Part two by trying every choice of twelve batteries, which grows exponentially with the bank,
+//! so it only copes with banks a few digits longer than twelve.
+
+/// Tries every way to turn on 12 batteries in every bank.
+pub fn solve2(input: &[Vec<u64>]) -> u64 {
+    input.iter()
+         .map(|bank| best_joltage(bank, 12, 0).expect("a bank has at least 12 batteries"))
+         .sum()
+}
+
+fn best_joltage(bank: &[u64], batteries: usize, joltage: u64) -> Option<u64> {
+    if batteries == 0 {
+        return Some(joltage);
+    }
+    (0..bank.len()).filter_map(|index| best_joltage(&bank[index + 1..], batteries - 1, joltage * 10 + bank[index]))
+                   .max()
+}
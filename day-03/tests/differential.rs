@@ -0,0 +1,20 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+fn banks() -> impl Strategy<Value = String> {
+    vec(vec(1u8..=9, 12..=16), 1..=3).prop_map(|banks| {
+        banks.iter()
+             .map(|bank| bank.iter().map(|digit| digit.to_string()).collect::<String>() + "\n")
+             .collect()
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn part2_matches_brute_force(text in banks()) {
+        let input = day_03::parse(&text).unwrap();
+        prop_assert_eq!(day_03::solve2(&input), day_03::reference::solve2(&input), "input:\n{}", text);
+    }
+}
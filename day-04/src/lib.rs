@@ -0,0 +1,97 @@
+use tracing::debug;
+use common::{Answer, ParseError, Scanner, Solution};
+use grid::{Grid, Point};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve2(input.clone()).into()
+    }
+}
+
+pub fn solve1(input: &Grid<char>) -> usize {
+    accessible(input).count()
+}
+
+pub fn solve2(mut input: Grid<char>) -> usize {
+    let mut removed = 0;
+    loop {
+        let res = accessible(&input).count();
+        if res == 0 { break };
+        removed += res;
+        debug!(removed = res, "accessible rolls removed");
+        input = clear_toilet_rolls(&input, accessible(&input));
+    }
+    removed
+}
+
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let width = lines.first()
+                     .map(|line| line.chars().count())
+                     .ok_or_else(|| ParseError::end_of_input(1, "a row of the grid"))?;
+
+    for (index, line) in lines.iter().enumerate() {
+        let mut scanner = Scanner::new(line, index + 1);
+        for _ in 0..width {
+            if !(scanner.eat('.') || scanner.eat('@')) {
+                return Err(scanner.error("'.' or '@'"));
+            }
+        }
+        scanner.expect_end()?;
+    }
+
+    Ok(Grid::from_lines(lines))
+}
+
+fn accessible(grid: &Grid<char>) -> impl Iterator<Item = Point> + '_ {
+    grid.points()
+        .filter(|p| grid.get(p) == Some(&'@'))
+        .filter(|p| {
+            let neighbour_at_count = grid.neighbours8(p)
+                .filter(|n| grid.get(n) == Some(&'@'))
+                .count();
+            neighbour_at_count < 4
+        })
+}
+
+fn clear_toilet_rolls(grid: &Grid<char>, to_clear: impl Iterator<Item = Point>) -> Grid<char> {
+    let mut new_grid = grid.clone();
+    for clearing in to_clear {
+        new_grid.set(&clearing, '.');
+    }
+    new_grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve1(&input), 13);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve2(input), 43);
+    }
+}
@@ -0,0 +1,158 @@
+use tracing::trace;
+use common::{Answer, ParseError, Scanner, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Problem;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve2(input).into()
+    }
+}
+
+pub fn solve1(problem: &Problem) -> usize {
+    problem.ingredients.iter()
+                       .filter(|ingredient| {
+                           problem.is_in_ranges(**ingredient)
+                       }).count()
+}
+
+pub fn solve2(problem: &Problem) -> u64 {
+    let mut work_board = problem.ranges.clone();
+
+    loop {
+        let mut merged = false;
+
+        for range in work_board.iter() {
+            let overlapping_indices: Vec<usize> =
+                find_overlapping_indices(range, &work_board);
+
+            if overlapping_indices.len() > 1 {
+                let overlapping_ranges: Vec<(u64, u64)> =
+                    remove_overlapping_ranges(&overlapping_indices,
+                                              &mut work_board);
+                let merged_range = calculate_new_range(&overlapping_ranges);
+                trace!(?overlapping_ranges, ?merged_range, "ranges merged");
+                work_board.push(merged_range);
+                merged = true;
+                break;
+            }
+        }
+
+        if !merged {
+            break;
+        }
+    }
+
+    work_board.iter().map(|(lb, ub)| { ub - lb + 1 }
+    ).sum()
+}
+
+fn find_overlapping_indices(range: &(u64, u64), distinct_ranges: &[(u64, u64)]) -> Vec<usize> {
+    distinct_ranges.iter()
+                   .enumerate()
+                   .filter(|(_, other)| {
+                       (range.0 >= other.0 && range.0 <= other.1)
+                       || (range.1 >= other.0 && range.1 <= other.1)
+                   })
+                   .map(|(index, _)| index)
+                   .collect()
+}
+
+fn remove_overlapping_ranges(overlapping_indices: &[usize],
+                             distinct_ranges: &mut Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    let mut indices = overlapping_indices.to_vec();
+    indices.sort_unstable(); // ascending
+    let mut removed = Vec::with_capacity(indices.len());
+
+    for i in indices.into_iter().rev() {
+        removed.push(distinct_ranges.remove(i));
+    }
+
+    removed
+}
+
+fn calculate_new_range(overlapping_ranges: &[(u64, u64)]) -> (u64, u64) {
+    (overlapping_ranges.iter().map(|range| range.0).min().unwrap(),
+     overlapping_ranges.iter().map(|range| range.1).max().unwrap())
+}
+
+/// Fresh ranges as `lower-upper`, an empty line, and then one ingredient ID per line.
+pub fn parse(input: &str) -> Result<Problem, ParseError> {
+    let mut ranges: Vec<(u64, u64)> = Vec::new();
+    let mut ingredients: Vec<u64> = Vec::new();
+    let mut parsing_ranges = true;
+
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            parsing_ranges = false;
+            continue
+        }
+        let mut scanner = Scanner::new(line, index + 1);
+        if parsing_ranges {
+            let lower = scanner.unsigned::<u64>()?;
+            scanner.expect('-')?;
+            let upper = scanner.unsigned::<u64>()?;
+            if upper < lower {
+                return Err(ParseError::new(index + 1, 1, "a range with lower <= upper", format!("'{}'", line)));
+            }
+            ranges.push((lower, upper))
+        } else {
+            ingredients.push(scanner.unsigned::<u64>()?)
+        }
+        scanner.expect_end()?;
+    }
+
+    Ok(Problem {
+        ranges,
+        ingredients
+    })
+}
+
+pub struct Problem {
+    ranges: Vec<(u64, u64)>,
+    ingredients: Vec<u64>
+}
+
+impl Problem {
+    fn is_in_ranges(&self, value: u64) -> bool {
+        self.ranges.iter().any(|range| value >= range.0 && value <= range.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve1(&input), 3);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve2(&input), 14);
+    }
+
+    #[test]
+    fn new_range_spans_all_overlapping_ranges() {
+        assert_eq!(calculate_new_range(&[(3, 5)]), (3, 5));
+        assert_eq!(calculate_new_range(&[(10, 14), (12, 18), (16, 20)]), (10, 20));
+        assert_eq!(calculate_new_range(&[(12, 18), (3, 5), (4, 13)]), (3, 18));
+    }
+}
@@ -0,0 +1,251 @@
+use tracing::trace;
+use common::{Answer, ParseError, Scanner, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Worksheet {
+            puzzle: parse(input)?,
+            puzzle_part2: parse_part2(input)?,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve1(&input.puzzle).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve2(&input.puzzle_part2).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+pub fn solve1(puzzle: &Puzzle) -> u64 {
+    puzzle.problems.iter().map(|problem| {
+            match problem.1 {
+                '*' => { problem.0.iter().product::<u64>() }
+                '+' => { problem.0.iter().sum() }
+                _ => panic!("Unexpected input")
+            }
+        }).sum()
+}
+
+/// `None` when a number does not fit in a `u64`.
+pub fn solve2(puzzle_part2: &PuzzlePart2) -> Option<u64> {
+    let mut res: u64 = 0;
+
+    let operator_indices: Vec<usize> =
+        puzzle_part2.problem.last()?.iter().enumerate()
+            .filter(|(_, value)| matches!(value, '+' | '*'))
+            .map(|(index, _)| index).collect();
+
+    let number_vertical_length = puzzle_part2.problem.len() - 1;
+
+    for i in 0..operator_indices.len() {
+        let start_index = operator_indices[i];
+        let mut end_index=
+            if i == operator_indices.len() - 1 {
+                puzzle_part2.problem[0].len() - 1
+            } else {
+                operator_indices[i + 1] - 2
+            };
+
+        let mut numbers:  Vec<u64> = Vec::new();
+
+        while end_index >= start_index {
+            let number_chars: String =
+                (0..number_vertical_length).map(|y| puzzle_part2.problem[y][end_index])
+                                              .filter(|x| x.is_ascii_digit())
+                                              .collect();
+
+            numbers.push(number_chars.parse::<u64>().ok()?);
+            if end_index == 0 { break }; // corner case
+            end_index -= 1;
+        }
+
+        let operator = puzzle_part2.problem.last()?[start_index];
+        trace!(?numbers, %operator, "problem read right to left");
+
+        res +=
+            match operator {
+                '*' => { numbers.iter().product::<u64>() }
+                '+' => { numbers.iter().sum() }
+                _ => panic!("Unexpected input")
+            }
+
+    }
+
+    Some(res)
+}
+
+/// Reads the worksheet row by row: lines of numbers followed by a line of operators.
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let mut raw_numbers: Vec<Vec<u64>> = Vec::new();
+    let mut operators: Vec<char> = Vec::new();
+    let mut line_count = 0;
+
+    for (index, line) in input.lines().enumerate() {
+        line_count = index + 1;
+        let mut scanner = Scanner::new(line, index + 1);
+        scanner.skip_whitespace();
+
+        if !operators.is_empty() {
+            return Err(scanner.error("end of input after the operators"));
+        }
+
+        let is_number = scanner.peek().is_some_and(|c| c.is_ascii_digit());
+        let mut row_numbers: Vec<u64> = Vec::new();
+        let mut row_operators: Vec<char> = Vec::new();
+
+        while !scanner.is_at_end() {
+            if is_number {
+                row_numbers.push(scanner.unsigned::<u64>()?);
+            } else {
+                match scanner.peek() {
+                    Some(operator @ ('+' | '*')) => {
+                        scanner.next_char();
+                        row_operators.push(operator)
+                    }
+                    _ => return Err(scanner.error("'+' or '*'")),
+                }
+            }
+            if !scanner.is_at_end() && !scanner.peek().is_some_and(char::is_whitespace) {
+                return Err(scanner.error("a space"));
+            }
+            scanner.skip_whitespace();
+        }
+
+        let count = row_numbers.len() + row_operators.len();
+        if raw_numbers.is_empty() {
+            raw_numbers = vec![Vec::new(); count];
+        }
+        if count == 0 || count != raw_numbers.len() {
+            return Err(ParseError::new(index + 1,
+                                       1,
+                                       format!("{} columns", raw_numbers.len()),
+                                       format!("{} columns", count)));
+        }
+
+        for (column, value) in row_numbers.into_iter().enumerate() {
+            raw_numbers[column].push(value);
+        }
+        operators.extend(row_operators);
+    }
+
+    if operators.is_empty() {
+        return Err(ParseError::end_of_input(line_count + 1, "a line of operators"));
+    }
+
+    let problems =
+        operators.iter()
+                 .enumerate()
+                 .map(|(index, operator)| {
+                     (raw_numbers[index].clone(),
+                      *operator)
+                 })
+                 .collect();
+
+    Ok(Puzzle {
+        problems,
+    })
+}
+
+/// Keeps the worksheet as characters, as the second part reads the numbers column by column.
+pub fn parse_part2(input: &str) -> Result<PuzzlePart2, ParseError> {
+    let mut problem: Vec<Vec<char>> = input.lines().map(|x| x.chars().collect()).collect();
+
+    let Some(operators) = problem.last() else {
+        return Err(ParseError::end_of_input(1, "a line of operators"));
+    };
+    let line_count = problem.len();
+    if let Some(column) = operators.iter().position(|c| !matches!(c, '+' | '*' | ' ')) {
+        return Err(ParseError::new(line_count,
+                                   column + 1,
+                                   "'+', '*' or ' '",
+                                   format!("'{}'", operators[column])));
+    }
+    if operators.first() != Some(&'+') && operators.first() != Some(&'*') {
+        return Err(ParseError::new(line_count, 1, "'+' or '*'", "a column without operator"));
+    }
+    for (index, line) in problem[..line_count - 1].iter().enumerate() {
+        if let Some(column) = line.iter().position(|c| !c.is_ascii_digit() && *c != ' ') {
+            return Err(ParseError::new(index + 1,
+                                       column + 1,
+                                       "a digit or ' '",
+                                       format!("'{}'", line[column])));
+        }
+    }
+
+    let max_length = problem.iter().map(|line| line.len()).max().unwrap();
+
+    for line_index in 0..problem.len() {
+        let line = problem.get_mut(line_index).unwrap();
+        let amount_needed = max_length - line.len();
+        (0..amount_needed).for_each(|_| line.push(' '));
+    }
+
+    // A blank column separates problems, the one left of the next operator, and nothing else
+    let operators = &problem[line_count - 1];
+    for column in 0..max_length {
+        let gap = operators.get(column + 1).is_some_and(|c| *c != ' ');
+        if gap && operators[column] != ' ' {
+            return Err(ParseError::new(line_count,
+                                       column + 2,
+                                       "' '",
+                                       format!("'{}'", operators[column + 1])));
+        }
+        let blank = problem[..line_count - 1].iter().all(|line| line[column] == ' ');
+        if blank && !gap {
+            return Err(ParseError::new(1, column + 1, "a digit", "a blank column"));
+        }
+    }
+
+    Ok(PuzzlePart2 {
+        problem
+    })
+}
+
+/// The two parts read the same worksheet in different directions.
+pub struct Worksheet {
+    puzzle: Puzzle,
+    puzzle_part2: PuzzlePart2,
+}
+
+pub struct Puzzle {
+    problems: Vec<(Vec<u64>, char)>
+}
+
+pub struct PuzzlePart2 {
+    problem: Vec<Vec<char>>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    #[test]
+    fn example_part1() {
+        let puzzle = parse(EXAMPLE).unwrap();
+        assert_eq!(solve1(&puzzle), 4277556);
+    }
+
+    #[test]
+    fn example_part2() {
+        let puzzle = parse_part2(EXAMPLE).unwrap();
+        assert_eq!(solve2(&puzzle), Some(3263827));
+    }
+
+    #[test]
+    fn problems_have_a_number_in_every_column() {
+        let error = parse_part2("1  2\n+  +\n").err().unwrap();
+        assert_eq!(error.to_string(), "1:2: expected a digit, found a blank column");
+        let error = parse_part2("12\n+*\n").err().unwrap();
+        assert_eq!(error.to_string(), "2:2: expected ' ', found '*'");
+    }
+}
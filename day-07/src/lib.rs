@@ -0,0 +1,183 @@
+use tracing::trace;
+use common::{Answer, ParseError, Scanner, Solution};
+use std::collections::{HashMap, VecDeque};
+use grid::{Grid, Point};
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve2(input).into()
+    }
+}
+
+pub fn solve2(grid: &Grid<char>) -> u64 {
+
+    let start =
+        grid.points().find(|point| grid.get(point) == Some(&'S')).unwrap();
+
+    let mut cache: HashMap<Point, u64> = HashMap::new();
+
+    do_solve2(grid, &start, &mut cache)
+}
+
+fn do_solve2(grid: &Grid<char>, point: &Point, cache: &mut HashMap<Point, u64>) -> u64 {
+    let down = Point::new(0, 1);
+    let left = Point::new(-1, 0);
+    let right = Point::new(1, 0);
+
+    let current_location = grid.get(point);
+
+    if cache.contains_key(point) {
+        return *cache.get(point).unwrap();
+    }
+
+    let rvalue =
+        match current_location {
+            Some('|') => {
+                { panic!("This should never happen")};
+            }
+            Some('S') => {
+                do_solve2(grid, &point.add(&down), cache)
+            },
+            Some('.') => {
+                do_solve2(grid, &point.add(&down), cache)
+            },
+            Some('^') => {
+                do_solve2(grid, &point.add(&left), cache) +
+                do_solve2(grid, &point.add(&right), cache)
+            }
+            None => {
+                1
+            },
+            _ => { panic!("Unexpected state")}
+        };
+
+    trace!(x = point.x, y = point.y, timelines = rvalue, "timelines counted");
+    cache.insert(*point, rvalue);
+
+    rvalue
+}
+
+pub fn solve1(mut grid: Grid<char>) -> u64 {
+    let mut dequeue: VecDeque<Point> = VecDeque::new();
+    let mut res = 0;
+
+    let start =
+        grid.points().find(|point| grid.get(point) == Some(&'S')).unwrap();
+
+    let down = Point::new(0, 1);
+    let left = Point::new(-1, 0);
+    let right = Point::new(1, 0);
+    dequeue.push_back(start);
+
+    while let Some(point) = dequeue.pop_front() {
+        let current_location = grid.get(&point);
+        match current_location  {
+            Some('|') => {
+                continue;
+            }
+            Some('S')  => {
+                dequeue.push_back(point.add(&down))
+            },
+            Some('.')  => {
+                grid.set(&point, '|');
+                dequeue.push_back(point.add(&down))
+            },
+            Some('^') => {
+                trace!(x = point.x, y = point.y, "beam split");
+                dequeue.push_back(point.add(&left));
+                dequeue.push_back(point.add(&right));
+                res += 1
+            },
+            None => {},
+            _ => { panic!("Unexpected input")}
+        }
+    }
+
+    res
+}
+
+/// The manifold is a grid of '.' and '^' with a single 'S' where the beam enters. Splitters are
+/// never side by side, a beam split into the next splitter would bounce between the two forever.
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let width = lines.first()
+                     .map(|line| line.chars().count())
+                     .ok_or_else(|| ParseError::end_of_input(1, "a row of the manifold"))?;
+    let mut start_found = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        let mut scanner = Scanner::new(line, index + 1);
+        let mut splitter = false;
+        for _ in 0..width {
+            if scanner.eat('.') {
+                splitter = false;
+                continue;
+            }
+            if !splitter && scanner.eat('^') {
+                splitter = true;
+                continue;
+            }
+            if !start_found && scanner.eat('S') {
+                start_found = true;
+                splitter = false;
+                continue;
+            }
+            return Err(scanner.error(match (splitter, start_found) {
+                (false, false) => "'.', '^' or 'S'",
+                (false, true) => "'.' or '^'",
+                (true, false) => "'.' or 'S'",
+                (true, true) => "'.'",
+            }));
+        }
+        scanner.expect_end()?;
+    }
+
+    if !start_found {
+        return Err(ParseError::end_of_input(lines.len() + 1, "a start position 'S'"));
+    }
+
+    Ok(Grid::from_lines(lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve1(input.clone()), 21);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve2(&input), 40);
+    }
+
+    #[test]
+    fn rejects_splitters_side_by_side() {
+        let error = parse("S.\n^^\n").unwrap_err();
+        assert_eq!(error.to_string(), "2:2: expected '.', found '^'");
+        assert_eq!(parse("S.\n^.\n").map(|grid| solve2(&grid)), Ok(2));
+    }
+}
@@ -0,0 +1,51 @@
+//! The manifold stepped through row by row and timeline by timeline, without the memo of the real
+//! solver. Timelines double at every splitter, so the manifolds have to be small.
+
+use std::collections::HashSet;
+
+use grid::{Grid, Point};
+
+/// Moves all beams down one row at a time, counting the splitters they hit.
+pub fn solve1(grid: &Grid<char>) -> u64 {
+    let start = start(grid);
+    let mut beams: HashSet<i32> = HashSet::from([start.x]);
+    let mut splits = 0;
+
+    for y in start.y + 1..grid.height() {
+        let mut next = HashSet::new();
+        for x in beams {
+            if grid.get(&Point::new(x, y)) == Some(&'^') {
+                splits += 1;
+                next.extend([x - 1, x + 1].into_iter().filter(|&x| grid.contains(&Point::new(x, y))));
+            } else {
+                next.insert(x);
+            }
+        }
+        beams = next;
+    }
+
+    splits
+}
+
+/// Follows every timeline on its own until it leaves the manifold.
+pub fn solve2(grid: &Grid<char>) -> u64 {
+    let start = start(grid);
+    timelines(grid, Point::new(start.x, start.y + 1))
+}
+
+fn timelines(grid: &Grid<char>, mut point: Point) -> u64 {
+    loop {
+        match grid.get(&point) {
+            None => return 1,
+            Some('^') => {
+                return timelines(grid, Point::new(point.x - 1, point.y)) +
+                       timelines(grid, Point::new(point.x + 1, point.y))
+            }
+            Some(_) => point = Point::new(point.x, point.y + 1),
+        }
+    }
+}
+
+fn start(grid: &Grid<char>) -> Point {
+    grid.points().find(|point| grid.get(point) == Some(&'S')).unwrap()
+}
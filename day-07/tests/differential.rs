@@ -0,0 +1,41 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// A manifold like the real ones: splitters on every other row, never next to each other or on
+/// the edge.
+fn manifolds() -> impl Strategy<Value = String> {
+    (3usize..=9).prop_flat_map(|width| (0..width, vec(vec(any::<bool>(), width), 1..=6)))
+                .prop_map(|(start, rows)| {
+                    let width = rows[0].len();
+                    let mut text: String = (0..width).map(|x| if x == start { 'S' } else { '.' }).collect();
+                    text.push('\n');
+                    for row in rows {
+                        text += &".".repeat(width);
+                        text.push('\n');
+                        let mut previous = false;
+                        for (x, splitter) in row.into_iter().enumerate() {
+                            let splitter = splitter && !previous && x > 0 && x < width - 1;
+                            text.push(if splitter { '^' } else { '.' });
+                            previous = splitter;
+                        }
+                        text.push('\n');
+                    }
+                    text
+                })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn part1_matches_brute_force(text in manifolds()) {
+        let grid = day_07::parse(&text).unwrap();
+        prop_assert_eq!(day_07::solve1(grid.clone()), day_07::reference::solve1(&grid), "input:\n{}", text);
+    }
+
+    #[test]
+    fn part2_matches_brute_force(text in manifolds()) {
+        let grid = day_07::parse(&text).unwrap();
+        prop_assert_eq!(day_07::solve2(&grid), day_07::reference::solve2(&grid), "input:\n{}", text);
+    }
+}
@@ -0,0 +1,163 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, trace};
+use common::{Answer, ParseError, Scanner, Solution};
+
+/// The puzzle input connects the 1000 closest pairs. The example connects 10, its test passes
+/// that to `solve`.
+const CONNECTIONS: usize = 1000;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input, Some(CONNECTIONS)).0.map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve(input, None).1.map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+/// Connects the closest pairs, `max_amount` of them or, without it, until everything is one
+/// circuit. Returns the product of the three largest circuits after `max_amount` connections, and
+/// the product of the X coordinates of the last pair that had to be connected, if it got that far.
+pub fn solve(points: &[Point], max_amount: Option<usize>) -> (Option<usize>, Option<i64>) {
+    let mut largest_circuits = None;
+    let mut last_connection = None;
+
+    let mut pair_2_distances: Vec<((usize, usize), f64)> =
+        (0..points.len()).flat_map(move |i| {
+            (i+1..points.len()).map(move |j|  ((i,j), points[i].distance(&points[j])  ) )
+        }).collect();
+    
+    pair_2_distances.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let mut clusters: Vec<HashSet<usize>> = Vec::new();
+
+    let mut index_2_cluster: HashMap<usize, usize> = HashMap::new();
+
+    for (index, pair_2_distance) in pair_2_distances.iter().enumerate() {
+        let l_id = pair_2_distance.0.0;
+        let r_id = pair_2_distance.0.1;
+
+        let l_cluster = index_2_cluster.get(&l_id).copied();
+        let r_cluster = index_2_cluster.get(&r_id).copied();
+
+        if l_cluster.is_none() && r_cluster.is_none() {
+            let cluster_id = clusters.len();
+            clusters.push(HashSet::new());
+            clusters[cluster_id].insert(l_id);
+            clusters[cluster_id].insert(r_id);
+            index_2_cluster.insert(l_id, cluster_id);
+            index_2_cluster.insert(r_id, cluster_id);
+        } else if l_cluster == r_cluster {
+            // do nothing
+        } else if let (Some(cluster_id), None) = (l_cluster, r_cluster) {
+            clusters[cluster_id].insert(r_id);
+            index_2_cluster.insert(r_id, cluster_id);
+        } else if let (None, Some(cluster_id)) = (l_cluster, r_cluster) {
+            clusters[cluster_id].insert(l_id);
+            index_2_cluster.insert(l_id, cluster_id);
+        } else {
+            // merge
+            let l_cluster_id = l_cluster.unwrap();
+            let r_cluster_id = r_cluster.unwrap();
+            trace!(left = l_cluster_id, right = r_cluster_id, "circuits merged");
+
+            let r_cluster_copy =  clusters.get(r_cluster_id).unwrap().clone();
+
+            let l_cluster = clusters.get_mut(l_cluster_id).unwrap();
+
+            for index_to_move in r_cluster_copy {
+                l_cluster.insert(index_to_move);
+                index_2_cluster.insert(index_to_move, l_cluster_id);
+            }
+
+            clusters.get_mut(r_cluster_id).unwrap().clear();
+        }
+
+        if Some(index + 1) == max_amount {
+            let mut cluster_copy = clusters.clone();
+            cluster_copy.sort_by_key(|cluster| Reverse(cluster.len()));
+
+            largest_circuits =
+                Some(cluster_copy.iter().take(3).fold(1, |acc, value| {acc * value.len()}));
+        }
+
+        if last_connection.is_none() && clusters.iter().any(|x| x.len() == points.len()) {
+            last_connection = Some(points[l_id].x * points[r_id].x);
+            debug!(connections = index + 1, "all junction boxes connected");
+        }
+
+        if max_amount.map_or(last_connection.is_some(), |max_amount| index + 1 >= max_amount) {
+            break;
+        }
+    }
+
+    (largest_circuits, last_connection)
+}
+
+/// One junction box per line as `x,y,z`.
+pub fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    input.lines().enumerate().map(|(index, line)| {
+        let mut scanner = Scanner::new(line, index + 1);
+        let x = scanner.number::<i64>()?;
+        scanner.expect(',')?;
+        let y = scanner.number::<i64>()?;
+        scanner.expect(',')?;
+        let z = scanner.number::<i64>()?;
+        scanner.expect_end()?;
+        Ok(Point::new(x, y, z))
+    }).collect()
+}
+
+pub struct Point {
+    x :i64,
+    y :i64,
+    z :i64,
+}
+
+impl Point {
+    fn distance(&self, other: &Self) -> f64 {
+        let dx = self.x as f64 - other.x as f64;
+        let dy = self.y as f64 - other.y as f64;
+        let dz = self.z as f64 - other.z as f64;
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    fn new(x: i64, y: i64, z: i64) -> Self {
+        Point {
+            x,
+            y,
+            z
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    #[test]
+    fn example_part1() {
+        let points = parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&points, Some(10)).0, Some(40));
+    }
+
+    #[test]
+    fn example_part2() {
+        let points = parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&points, None).1, Some(25272));
+    }
+}
@@ -0,0 +1,209 @@
+use std::cmp::{max, min, Reverse};
+use std::collections::HashMap;
+use tracing::{debug, trace};
+use common::{Answer, ParseError, Scanner, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve1(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    /// The scan in `solve2` is not finished, its answers would only be wrong.
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// The largest rectangle with red tiles in opposite corners, `None` for fewer than two tiles.
+pub fn solve1(points: &[Point]) -> Option<i64> {
+
+    let mut pair_2_surface: Vec<((usize, usize), i64)> =
+        (0..points.len()).flat_map(move |i| {
+            (i+1..points.len()).map(move |j|  ((i,j), points[i].surface(&points[j])  ) )
+        }).collect();
+
+    pair_2_surface.sort_by_key(|pair| Reverse(pair.1));
+    let &(corners, surface) = pair_2_surface.first()?;
+    debug!(?corners, surface, "largest rectangle");
+
+    Some(surface)
+}
+
+pub fn solve2(points: &[Point]) -> i64 {
+    let mut x_ranges: HashMap<i64, Vec<(i64, i64)>> = HashMap::new();
+    let mut y_ranges: HashMap<i64, Vec<(i64, i64)>> = HashMap::new();
+
+    let mut new_points = points.to_vec();
+    new_points.push(new_points[0].clone());
+
+    for i in 1..new_points.len() {
+        let previous = &new_points[i-1];
+        let current = &new_points[i];
+        if previous.x == current.x {
+            let min_y = min(previous.y, current.y);
+            let max_y = max(previous.y, current.y);
+            x_ranges.entry(current.y).or_default().push((min_y, max_y));
+        } else if previous.y == current.y {
+            let min_x = min(previous.x, current.x);
+            let max_x = max(previous.x, current.x);
+            y_ranges.entry(current.y).or_default().push((min_x, max_x));
+        }
+    }
+
+    let min_y = points.iter().map(|point| point.y).min().unwrap();
+    let max_y = points.iter().map(|point| point.y).max().unwrap();
+
+    let max_square: i64 = 0;
+
+    // Scanning from top to bottom
+    let mut active_ranges:  Vec<((i64,i64),i64)> = Vec::new();
+
+    for y in min_y..(max_y + 1) {
+        let new_ranges = x_ranges.get(&y);
+        if new_ranges.is_none() { continue };
+
+        let edges = new_ranges.unwrap();
+        trace!(y, ?edges, "scanning edges");
+        for edge in edges {
+            let to_remove = find_overlapping_indices_reversed(edge, &active_ranges);
+            let affected_ranges: Vec<((i64,i64),i64)>
+                = to_remove.iter().map(|index| active_ranges.remove(*index)).collect();
+            for affected_range in affected_ranges {
+                let range_edge = affected_range.0;
+                let intersection = intersection(&range_edge, edge).unwrap();
+                let _remaining = remove_range_from_range(&range_edge, &intersection);
+            }
+        }
+
+        // Find all ranges we overlap with 2 and remove those active parts
+        // After calculating their sizes
+
+        // Find all ranges without overlap and create new active ranges from them
+
+        // Find all ranges we overlap with 1 and merge those as new
+
+    }
+    assert_eq!(active_ranges.len(), 0);
+
+    // Scanning for left to right
+    max_square
+}
+
+fn find_overlapping_indices_reversed(edge: &(i64,i64),
+                                     active_squares: &[((i64,i64),i64)]) -> Vec<usize> {
+    active_squares.iter()
+                  .enumerate()
+                  .filter(|(_, (square_edge, _))| {
+                      let r = intersection(edge, square_edge);
+                      if r.is_none() {
+                          return false;
+                      }
+                      let r = r.unwrap();
+                      r.0 != r.1
+                  })
+                 .map(|(index, _)| index)
+                 .rev()
+                 .collect()
+}
+
+fn intersection(left: &(i64, i64), right: &(i64, i64)) -> Option<(i64, i64)> {
+        if left.0 >= right.0 && left.0 <= right.1 {
+            let l = max(left.0, right.0);
+            let r = min(left.1, right.1);
+            Some((l,r))
+        } else if right.0 >= left.0 && right.0 <= left.1 {
+            let l = max(right.0, left.0);
+            let r = min(right.1, left.1);
+            Some((l,r))
+        } else {
+            None
+        }
+}
+
+fn remove_range_from_range(_original: &(i64, i64), _to_delete:  &(i64, i64)) -> Vec<(i64, i64)> {
+    Vec::new()
+}
+
+/// One red tile per line as `x,y`.
+pub fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    input.lines().enumerate().map(|(index, line)| {
+        let mut scanner = Scanner::new(line, index + 1);
+        let x = scanner.number::<i64>()?;
+        scanner.expect(',')?;
+        let y = scanner.number::<i64>()?;
+        scanner.expect_end()?;
+        Ok(Point::new(x, y))
+    }).collect()
+}
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+pub struct Point {
+    x: i64,
+    y: i64
+}
+
+impl Point {
+    fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    fn surface(&self, other: &Point)  -> i64 {
+        let min_x = min(self.x, other.x);
+        let max_x = max(self.x, other.x);
+        let min_y = min(self.y, other.y);
+        let max_y = max(self.y, other.y);
+
+        ((max_x -  min_x) + 1) * ((max_y - min_y) + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    #[test]
+    fn example_part1() {
+        let points = parse(EXAMPLE).unwrap();
+        assert_eq!(solve1(&points), Some(50));
+    }
+
+    #[test]
+    fn answers_only_what_is_solved() {
+        let points = parse("7,1\n").unwrap();
+        assert_eq!(Day09::part1(&points), Answer::Unsolved);
+        assert_eq!(Day09::part2(&parse(EXAMPLE).unwrap()), Answer::Unsolved);
+    }
+
+    #[test]
+    #[ignore = "the second part is not solved yet"]
+    fn example_part2() {
+        let points = parse(EXAMPLE).unwrap();
+        assert_eq!(solve2(&points), 24);
+    }
+
+    #[test]
+    fn overlapping_ranges_intersect() {
+        assert_eq!(intersection(&(1, 5), &(3, 8)), Some((3, 5)));
+        assert_eq!(intersection(&(3, 8), &(1, 5)), Some((3, 5)));
+        assert_eq!(intersection(&(1, 10), &(4, 6)), Some((4, 6)));
+        assert_eq!(intersection(&(1, 5), &(5, 8)), Some((5, 5)));
+    }
+
+    #[test]
+    fn disjoint_ranges_do_not_intersect() {
+        assert_eq!(intersection(&(1, 4), &(5, 8)), None);
+        assert_eq!(intersection(&(5, 8), &(1, 4)), None);
+    }
+}
@@ -0,0 +1,260 @@
+use rayon::prelude::*;
+use good_lp::{
+    variable, Expression, ProblemVariables, Solution as _, SolverModel,
+    solvers::highs::highs,
+};
+use tracing::debug;
+use common::{Answer, ParseError, Scanner, Solution};
+use common::progress::ParallelProgress;
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve2(input).into()
+    }
+}
+
+pub fn solve1(machines: &[Machine]) -> i64 {
+    machines
+        .par_iter()
+        .progress()
+        .map(|machine| {
+            solve_toggle_ilp(machine).unwrap()
+        })
+        .sum()
+}
+
+pub fn solve2(machines: &[Machine]) -> i64 {
+    machines
+        .par_iter()
+        .progress()
+        .map(|machine| {
+            solve_exact(machine).unwrap()
+        })
+        .sum()
+}
+
+fn solve_toggle_ilp(machine: &Machine) -> Option<i64> {
+    let n = machine.desired_end_state.len();
+    let b = machine.button_2_switches.len();
+
+    // Build reverse map: switch i -> buttons that toggle it
+    let mut switch_to_buttons: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (bi, sws) in machine.button_2_switches.iter().enumerate() {
+        for &i in sws {
+            if i >= n {
+                return None; // invalid input
+            }
+            switch_to_buttons[i].push(bi);
+        }
+    }
+
+    let mut vars = ProblemVariables::new();
+
+    // x_b ∈ {0,1}
+    let x: Vec<_> = (0..b)
+        .map(|bi| vars.add(variable().binary().name(format!("x_{bi}"))))
+        .collect();
+
+    // For each switch i: integer k_i >= 0
+    // Optional but helpful: k_i <= deg_i/2 because sum a_{i,b} x_b <= deg_i
+    let k: Vec<_> = (0..n)
+        .map(|i| {
+            let deg_i = switch_to_buttons[i].len() as f64;
+            vars.add(variable().integer().min(0.0).max((deg_i / 2.0).floor()).name(format!("k_{i}")))
+        })
+        .collect();
+
+    // Objective: minimize total presses
+    let objective: Expression = x.iter().copied().sum();
+    let mut model = vars.minimise(objective).using(highs); // <-- NOTE: highs, not highs()
+
+    for i in 0..n {
+        let mut sum_i = Expression::from(0.0);
+        for &bi in &switch_to_buttons[i] {
+            sum_i += x[bi];
+        }
+        let t_i = if machine.desired_end_state[i] { 1.0 } else { 0.0 };
+
+        // sum_i - 2*k_i == t_i  (enforces parity/XOR)
+        model = model.with((sum_i - 2.0 * k[i]).eq(t_i));
+    }
+
+    let solution = model.solve().ok()?;
+
+    // Minimum #presses is sum of x_b (they're 0/1)
+    let total: i64 = x.iter().map(|&v| solution.value(v).round() as i64).sum();
+    debug!(lights = n, buttons = b, presses = total, "lights configured");
+    Some(total)
+}
+
+fn solve_exact(machine: &Machine) -> Option<i64> {
+    let n = machine.joltage.len();
+    let b = machine.button_2_switches.len();
+
+    // Precompute: for each switch i, which buttons affect it?
+    let mut switch_to_buttons: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (bi, sws) in machine.button_2_switches.iter().enumerate() {
+        for &i in sws {
+            switch_to_buttons[i].push(bi);
+        }
+    }
+
+    // Decision variables: x[b] are nonnegative integers
+    let mut vars = ProblemVariables::new();
+    let x: Vec<_> = (0..b)
+        .map(|bi| vars.add(variable().integer().min(0.0).name(format!("x_{bi}"))))
+        .collect();
+
+    // Objective: minimize total presses
+    let objective: Expression = x.iter().copied().sum();
+    let mut model = vars.minimise(objective).using(highs);
+
+    // Exact constraints: for each i, sum_{b affects i} x_b == joltage[i]
+    for (i, buttons) in switch_to_buttons.iter().enumerate() {
+        let mut s_i = Expression::from(0.0);
+        for &bi in buttons {
+            s_i += x[bi];
+        }
+        model = model.with(s_i.eq(machine.joltage[i] as f64));
+    }
+
+    // Solve
+    let solution = model.solve().ok()?;
+
+    // Minimal total presses is the objective value = sum_b x_b
+    // (we recompute from variable values to avoid relying on solver APIs)
+    let total: i64 = x
+        .iter()
+        .map(|&v| solution.value(v).round() as i64)
+        .sum();
+
+    debug!(counters = n, buttons = b, presses = total, "joltage configured");
+    Some(total)
+}
+
+/// One machine per line: `[.##.]` lights, `(1,3)` buttons and `{3,5,4,7}` joltage levels.
+pub fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input.lines().enumerate().map(|(index, line)| {
+        let mut scanner = Scanner::new(line, index + 1);
+
+        scanner.expect('[')?;
+        let mut desired_end_state: Vec<bool> = Vec::new();
+        loop {
+            if scanner.eat('.') {
+                desired_end_state.push(false);
+            } else if scanner.eat('#') {
+                desired_end_state.push(true);
+            } else if desired_end_state.is_empty() {
+                return Err(scanner.error("'.' or '#'"));
+            } else {
+                scanner.expect(']')?;
+                break;
+            }
+        }
+        let n = desired_end_state.len();
+
+        let mut button_2_switches : Vec<Vec<usize>> = Vec::new();
+        let mut joltage: Option<Vec<i64>> = None;
+
+        scanner.skip_whitespace();
+        while !scanner.is_at_end() {
+            if joltage.is_some() {
+                return Err(scanner.error("end of line after the joltage levels"));
+            }
+            if scanner.eat('(') {
+                let mut switches = Vec::new();
+                loop {
+                    let column = scanner.column();
+                    let switch = scanner.unsigned::<usize>()?;
+                    if switch >= n {
+                        return Err(ParseError::new(index + 1,
+                                                   column,
+                                                   format!("a light below {}", n),
+                                                   format!("'{}'", switch)));
+                    }
+                    switches.push(switch);
+                    if !scanner.eat(',') {
+                        break;
+                    }
+                }
+                scanner.expect(')')?;
+                button_2_switches.push(switches);
+            } else if scanner.eat('{') {
+                let column = scanner.column();
+                let mut levels = Vec::new();
+                loop {
+                    levels.push(scanner.unsigned::<i64>()?);
+                    if !scanner.eat(',') {
+                        break;
+                    }
+                }
+                if levels.len() != n {
+                    return Err(ParseError::new(index + 1,
+                                               column,
+                                               format!("{} joltage levels", n),
+                                               format!("{} levels", levels.len())));
+                }
+                scanner.expect('}')?;
+                joltage = Some(levels);
+            } else {
+                return Err(scanner.error("'(' or '{'"));
+            }
+            if !scanner.is_at_end() && !scanner.peek().is_some_and(char::is_whitespace) {
+                return Err(scanner.error("a space"));
+            }
+            scanner.skip_whitespace();
+        }
+
+        let joltage = joltage.ok_or_else(|| scanner.error("'{'"))?;
+
+        Ok(Machine {
+            desired_end_state,
+            button_2_switches,
+            joltage
+        })
+    }).collect()
+}
+
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+pub struct Machine {
+    desired_end_state: Vec<bool>,
+    button_2_switches: Vec<Vec<usize>>,
+    joltage: Vec<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve1(&input), 7);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve2(&input), 33);
+    }
+}
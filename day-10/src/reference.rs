@@ -0,0 +1,53 @@
+//! Both parts by exhaustive search instead of a linear program, for machines with a few buttons and
+//! low joltage levels.
+
+use crate::Machine;
+
+/// Tries every set of buttons, as pressing a button twice undoes the first press.
+pub fn solve1(machines: &[Machine]) -> i64 {
+    machines.iter().map(fewest_toggles).sum()
+}
+
+/// Tries every number of presses for every button, up to the lowest level the button raises.
+pub fn solve2(machines: &[Machine]) -> i64 {
+    machines.iter()
+            .map(|machine| {
+                let mut levels = machine.joltage.clone();
+                fewest_presses(&machine.button_2_switches, &mut levels)
+                    .expect("the joltage levels can be reached")
+            })
+            .sum()
+}
+
+fn fewest_toggles(machine: &Machine) -> i64 {
+    let buttons = &machine.button_2_switches;
+    (0u32..1 << buttons.len())
+        .filter(|pressed| {
+            let mut lights = vec![false; machine.desired_end_state.len()];
+            for (index, button) in buttons.iter().enumerate() {
+                if pressed & (1 << index) != 0 {
+                    for &light in button {
+                        lights[light] = !lights[light];
+                    }
+                }
+            }
+            lights == machine.desired_end_state
+        })
+        .map(|pressed| pressed.count_ones() as i64)
+        .min()
+        .expect("the lights can be configured")
+}
+
+fn fewest_presses(buttons: &[Vec<usize>], levels: &mut [i64]) -> Option<i64> {
+    let Some((button, rest)) = buttons.split_first() else {
+        return levels.iter().all(|&level| level == 0).then_some(0);
+    };
+
+    let most = button.iter().map(|&light| levels[light]).min().unwrap_or(0);
+    (0..=most).filter_map(|presses| {
+        button.iter().for_each(|&light| levels[light] -= presses);
+        let fewest = fewest_presses(rest, levels).map(|fewest| fewest + presses);
+        button.iter().for_each(|&light| levels[light] += presses);
+        fewest
+    }).min()
+}
@@ -0,0 +1,46 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// Machines with up to four lights and buttons. The lights and joltage levels come from pressing
+/// the buttons, so both parts always have an answer.
+fn machines() -> impl Strategy<Value = String> {
+    let machine = (1usize..=4).prop_flat_map(|lights| vec((vec(any::<bool>(), lights), 0i64..=5), 1..=4))
+                              .prop_map(|buttons| {
+        let lights = buttons[0].0.len();
+        let mut on = vec![false; lights];
+        let mut joltage = vec![0; lights];
+        let mut wiring = Vec::new();
+        for (toggles, presses) in buttons {
+            let mut button: Vec<usize> = (0..lights).filter(|&light| toggles[light]).collect();
+            if button.is_empty() {
+                button.push(0);
+            }
+            for &light in &button {
+                on[light] ^= presses % 2 == 1;
+                joltage[light] += presses;
+            }
+            let button: Vec<String> = button.iter().map(usize::to_string).collect();
+            wiring.push(format!("({})", button.join(",")));
+        }
+        let on: String = on.iter().map(|&on| if on { '#' } else { '.' }).collect();
+        let joltage: Vec<String> = joltage.iter().map(i64::to_string).collect();
+        format!("[{}] {} {{{}}}\n", on, wiring.join(" "), joltage.join(","))
+    });
+    vec(machine, 1..=3).prop_map(|machines| machines.concat())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(4000))]
+
+    #[test]
+    fn part1_matches_brute_force(text in machines()) {
+        let input = day_10::parse(&text).unwrap();
+        prop_assert_eq!(day_10::solve1(&input), day_10::reference::solve1(&input), "input:\n{}", text);
+    }
+
+    #[test]
+    fn part2_matches_brute_force(text in machines()) {
+        let input = day_10::parse(&text).unwrap();
+        prop_assert_eq!(day_10::solve2(&input), day_10::reference::solve2(&input), "input:\n{}", text);
+    }
+}
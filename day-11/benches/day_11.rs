@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_11::Day11;
+
+fn bench(c: &mut Criterion) {
+    common::bench::<Day11>(c, "input/test.dat", &[1]);
+    common::bench::<Day11>(c, "input/test2.dat", &[2]);
+    common::bench::<Day11>(c, "input/input.dat", &[1, 2]);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
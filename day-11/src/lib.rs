@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use tracing::debug;
+use common::{Answer, ParseError, Scanner, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = HashMap<String, Vec<String>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve2(input).into()
+    }
+}
+
+pub fn solve1(input: &HashMap<String, Vec<String>>) -> u128 {
+    let mut cache: HashMap<String, u128> = HashMap::new();
+    do_solve1(&"you".to_string(), input, &mut cache)
+}
+
+fn do_solve1(location: &String,
+             input: &HashMap<String, Vec<String>>,
+             cache: &mut HashMap<String, u128>) -> u128 {
+
+    if cache.contains_key(location) {
+        return *(cache.get(location).unwrap())
+    }
+
+    let answer =
+        if location == "out" {
+            1
+        } else {
+            input.get(location)
+                 .map(|outputs| {
+                     outputs.iter().map(|output| do_solve1(output, input, cache)).sum()
+                 }).unwrap_or(0)
+        };
+
+    cache.insert(location.clone(), answer);
+
+    answer
+}
+
+pub fn solve2(input: &HashMap<String, Vec<String>>) -> u128 {
+    let start = "svr".to_string();
+    let end = "out".to_string();
+    let dac = "dac".to_string();
+    let fft = "fft".to_string();
+
+    let mut cache: HashMap<String, u128> = HashMap::new();
+    let from_start_to_dac_no_fft = calculate_from_to(&start, &dac, Some(&fft), input, &mut cache);
+
+    let mut cache: HashMap<String, u128> = HashMap::new();
+    let from_dac_to_fft = calculate_from_to(&dac, &fft, None, input, &mut cache);
+
+    let mut cache: HashMap<String, u128> = HashMap::new();
+    let from_fft_to_out_no_dac =  calculate_from_to(&fft, &end, Some(&dac), input, &mut cache);
+
+    let from_start_to_dac_to_fft_to_end =
+        from_start_to_dac_no_fft * from_dac_to_fft * from_fft_to_out_no_dac;
+    debug!(from_start_to_dac_no_fft, from_dac_to_fft, from_fft_to_out_no_dac, "paths through dac, then fft");
+
+    let mut cache: HashMap<String, u128> = HashMap::new();
+    let from_start_to_fft_no_dac = calculate_from_to(&start, &fft, Some(&dac), input, &mut cache);
+
+    let mut cache: HashMap<String, u128> = HashMap::new();
+    let from_fft_to_dac = calculate_from_to(&fft, &dac, None, input, &mut cache);
+
+    let mut cache: HashMap<String, u128> = HashMap::new();
+    let from_dac_to_out_no_fft =  calculate_from_to(&dac, &end, Some(&fft), input, &mut cache);
+
+    let from_start_to_fft_to_dac_to_end =
+        from_start_to_fft_no_dac * from_fft_to_dac * from_dac_to_out_no_fft;
+    debug!(from_start_to_fft_no_dac, from_fft_to_dac, from_dac_to_out_no_fft, "paths through fft, then dac");
+
+    from_start_to_dac_to_fft_to_end + from_start_to_fft_to_dac_to_end
+}
+
+fn calculate_from_to(location: &String,
+                     end: &String,
+                     forbidden: Option<&String>,
+                     input: &HashMap<String, Vec<String>>,
+                     cache: &mut HashMap<String, u128>) -> u128 {
+
+    if cache.contains_key(location) {
+        return *(cache.get(location).unwrap())
+    }
+
+    let answer =
+        if location == end {
+            1
+        } else if forbidden.is_some() && forbidden.unwrap() == location {
+            0
+        } else {
+            input.get(location)
+                .map(|outputs| {
+                    outputs.iter().map(|output|
+                        calculate_from_to(output,
+                                          end,
+                                          forbidden,
+                                          input,
+                                          cache)).sum()
+                }).unwrap_or(0)
+        };
+
+    cache.insert(location.clone(), answer);
+
+    answer
+ }
+
+/// One device per line as `name: output output ...`.
+pub fn parse(input: &str) -> Result<HashMap<String, Vec<String>>, ParseError> {
+    input.lines().enumerate().map(|(index, line)| {
+        let mut scanner = Scanner::new(line, index + 1);
+        let key = scanner.take_while(|c| c != ':' && !c.is_whitespace());
+        if key.is_empty() {
+            return Err(scanner.error("a device name"));
+        }
+        scanner.expect(':')?;
+        scanner.skip_whitespace();
+        let mut values = Vec::new();
+        while !scanner.is_at_end() {
+            values.push(scanner.word()?.to_string());
+            scanner.skip_whitespace();
+        }
+        Ok((key.to_string(), values))
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+    const EXAMPLE_PART2: &str = include_str!("../input/test2.dat");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(solve1(&input), 5);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE_PART2).unwrap();
+        assert_eq!(solve2(&input), 2);
+    }
+
+    #[test]
+    fn no_paths_from_a_missing_device() {
+        // The second example has no `you`, the first no `svr`
+        assert_eq!(solve1(&parse(EXAMPLE_PART2).unwrap()), 0);
+        assert_eq!(solve2(&parse(EXAMPLE).unwrap()), 0);
+    }
+}
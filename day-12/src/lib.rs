@@ -0,0 +1,458 @@
+use std::collections::{HashSet, HashMap};
+use std::fmt;
+
+use tracing::{debug, trace};
+use common::{Answer, ParseError, Scanner, Solution};
+use common::progress::ParallelProgress;
+use grid::{Grid, Point};
+use rayon::prelude::*;
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Problem;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve1(input).into()
+    }
+
+    /// The last day only has a single puzzle.
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+pub fn solve1(problem: &Problem) -> usize {
+    let piece_index_to_possible_rotations: Vec<Vec<Piece>> =
+        problem.pieces.iter()
+                      .map(|piece| piece.find_unique_transformation_results())
+                      .collect();
+
+    problem.puzzles
+        .par_iter()
+        .progress()
+        .filter(|puzzle| can_fit(&piece_index_to_possible_rotations, puzzle))
+        .count()
+}
+
+fn can_fit(piece_index_to_possible_rotations: &Vec<Vec<Piece>>,
+           puzzle: &Puzzle) -> bool {
+
+    let grid = empty_grid(puzzle);
+
+    let mut index_of_pieces_to_place: Vec<usize> = Vec::new();
+
+    for (index, amount) in puzzle.amount_of_pieces_to_place.iter().enumerate() {
+        for _ in 0..*amount {
+            index_of_pieces_to_place.push(index);
+        }
+    }
+
+    let mut cache: HashMap<Grid<char>, usize> = HashMap::new();
+    let fits = can_fit_piece_on_grid(0,
+                                     &index_of_pieces_to_place,
+                                     piece_index_to_possible_rotations,
+                                     &grid,
+                                     &mut cache).is_some();
+    debug!(width = puzzle.grid_x_size, height = puzzle.grid_y_size, fits, "region done");
+    fits
+}
+
+fn calculate_required_space(index: usize,
+                            index_of_pieces_to_place: &[usize],
+                            piece_index_to_possible_rotations: &[Vec<Piece>]) -> usize {
+    let mut total = 0;
+    for &piece_type in index_of_pieces_to_place.iter().skip(index) {
+        // Get the first shape from the possible rotations
+        if let Some(first_shape) = piece_index_to_possible_rotations.get(piece_type)
+            .and_then(|shapes| shapes.first()) {
+            total += piece_area(first_shape);
+        }
+    }
+    total
+}
+
+fn piece_area(piece: &Piece) -> usize {
+    piece.shape.iter()
+        .flat_map(|row| row.iter())
+        .filter(|&&ch| ch == '#')
+        .count()
+}
+
+fn can_fit_piece_on_grid(index: usize,
+                         index_of_pieces_to_place: &Vec<usize>,
+                         piece_index_to_possible_rotations: &Vec<Vec<Piece>>,
+                         grid: &Grid<char>,
+                         cache: &mut HashMap<Grid<char>, usize>) -> Option<Grid<char>> {
+
+    if index >= index_of_pieces_to_place.len() {
+        return Some(grid.clone())
+    }
+
+    if let Some(&cached_fail_index) = cache.get(grid) {
+        if index >= cached_fail_index {
+            return None;
+        }
+    }
+
+    let empty_spaces = empty_spaces(grid);
+    let required_space = calculate_required_space(index, index_of_pieces_to_place, piece_index_to_possible_rotations);
+    if empty_spaces < required_space {
+        cache.insert(grid.clone(), index);
+        return None;
+    }
+
+    let index_of_piece = index_of_pieces_to_place[index];
+
+    let possible_formations = &piece_index_to_possible_rotations[index_of_piece];
+
+    for possible_formation in possible_formations {
+        for point in grid.points_column_major() {
+            let Some(next_grid) = place_piece_at(grid, possible_formation, &point) else {
+                trace!(piece = index_of_piece, x = point.x, y = point.y, "piece does not fit");
+                continue;
+            };
+            trace!(piece = index_of_piece, x = point.x, y = point.y, "piece placed");
+            let next = can_fit_piece_on_grid(index  + 1,
+                                             index_of_pieces_to_place,
+                                             piece_index_to_possible_rotations,
+                                             &next_grid,
+                                             cache);
+            if next.is_some() {
+                return next;
+            }
+        }
+    }
+
+    cache.insert(grid.clone(), index);
+    None
+}
+
+/// Numbered piece shapes separated by blank lines, followed by one `WxH: counts` region per line.
+pub fn parse(input: &str) -> Result<Problem, ParseError> {
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut puzzles: Vec<Puzzle> = Vec::new();
+
+    let mut it = input.lines().enumerate().peekable();
+
+    // parse pieces, the first line mentioning an 'x' starts the regions
+    while let Some(&(index, line)) = it.peek() {
+        if line.contains('x') {
+            break;
+        }
+        it.next();
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mut scanner = Scanner::new(line, index + 1);
+        let column = scanner.column();
+        let piece_index = scanner.unsigned::<usize>()?;
+        if piece_index != pieces.len() {
+            return Err(ParseError::new(index + 1,
+                                       column,
+                                       format!("piece {}", pieces.len()),
+                                       format!("piece {}", piece_index)));
+        }
+        scanner.expect(':')?;
+        scanner.expect_end()?;
+
+        let mut shape: Vec<Vec<char>> = Vec::new();
+        while let Some(&(index, line)) = it.peek() {
+            if line.trim().is_empty() || line.contains('x') {
+                break;
+            }
+            it.next();
+
+            let mut scanner = Scanner::new(line, index + 1);
+            let row = scanner.take_while(|c| c == '#' || c == '.');
+            if row.is_empty() || shape.first().is_some_and(|first| first.len() != row.len()) {
+                return Err(scanner.error("a row of '#' and '.' as wide as the first"));
+            }
+            scanner.expect_end()?;
+            shape.push(row.chars().collect());
+        }
+
+        if shape.is_empty() {
+            return Err(ParseError::end_of_input(index + 2, "the rows of the piece"));
+        }
+
+        pieces.push(Piece {
+            shape
+        })
+    }
+
+    for (index, line) in it {
+        let mut scanner = Scanner::new(line, index + 1);
+        let grid_x_size = scanner.unsigned::<usize>()?;
+        scanner.expect('x')?;
+        let grid_y_size = scanner.unsigned::<usize>()?;
+        scanner.expect(':')?;
+        scanner.skip_whitespace();
+
+        let mut amount_of_pieces_to_place: Vec<usize> = Vec::new();
+        while !scanner.is_at_end() {
+            if amount_of_pieces_to_place.len() == pieces.len() {
+                return Err(scanner.error(format!("{} piece counts", pieces.len())));
+            }
+            amount_of_pieces_to_place.push(scanner.unsigned::<usize>()?);
+            scanner.skip_whitespace();
+        }
+        if amount_of_pieces_to_place.len() != pieces.len() {
+            return Err(scanner.error(format!("{} piece counts", pieces.len())));
+        }
+
+        puzzles.push(Puzzle {
+            grid_x_size,
+            grid_y_size,
+            amount_of_pieces_to_place
+        })
+    }
+
+
+    Ok(Problem {
+        pieces,
+        puzzles
+    })
+}
+
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+pub struct Problem {
+    pieces: Vec<Piece>,
+    puzzles: Vec<Puzzle>
+}
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+struct Piece {
+    shape: Vec<Vec<char>>
+}
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+struct Puzzle {
+    grid_x_size: usize,
+    grid_y_size: usize,
+    amount_of_pieces_to_place: Vec<usize>
+}
+
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+enum Rotation {
+    Degree0,
+    Degree90,
+    Degree180,
+    Degree270
+}
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+enum Flip {
+    None,
+    Horizontal,
+    Vertical
+}
+
+fn empty_grid(puzzle: &Puzzle) -> Grid<char> {
+    Grid::new(puzzle.grid_x_size, puzzle.grid_y_size, '.')
+}
+
+fn place_piece_at(grid: &Grid<char>, piece: &Piece, position: &Point) -> Option<Grid<char>> {
+    let mut next_state = grid.clone();
+    for point in piece.points() {
+        let grid_pos = position.add(&point);
+        let char_to_update = next_state.get(&grid_pos);
+        if char_to_update != Some(&'.') {
+            return None
+        }
+        next_state.set(&grid_pos, '#');
+    }
+    Some(next_state)
+}
+
+fn empty_spaces(grid: &Grid<char>) -> usize {
+    grid.values()
+        .filter(|&&ch| ch == '.')
+        .count()
+}
+
+impl fmt::Display for Piece {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.shape {
+            for &ch in row {
+                write!(f, "{}", ch)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Piece {
+    fn transform(&self, transformation: (Rotation, Flip)) -> Self {
+        let (rot, flip) = transformation;
+
+        let rotated = rotate_grid(&self.shape, rot);
+        let flipped = flip_grid(&rotated, flip);
+
+        Piece { shape: flipped }
+    }
+
+    fn find_unique_transformation_results(&self) -> Vec<Self> {
+        let rotations = [
+            Rotation::Degree0,
+            Rotation::Degree90,
+            Rotation::Degree180,
+            Rotation::Degree270,
+        ];
+        let flips = [Flip::None, Flip::Horizontal, Flip::Vertical];
+
+        let mut set: HashSet<Piece> = HashSet::new();
+
+        for r in rotations.iter() {
+            for f in flips.iter() {
+                set.insert(self.transform((r.clone(), f.clone())));
+            }
+        }
+
+        set.into_iter().collect()
+    }
+
+    fn height(&self) -> i32 {
+        self.shape.len() as i32
+    }
+
+    fn width(&self) -> i32  {
+        self.shape.first().unwrap().len() as i32
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        let w = self.width();
+        let h = self.height();
+        let shape = &self.shape;
+
+        (0..w).flat_map(move |x| {
+            (0..h).filter_map(move |y| {
+                if shape[y as usize][x as usize] == '#' {
+                    Some(Point::new(x, y))
+                } else {
+                    None
+                }
+            })
+        })
+    }
+}
+
+fn rotate_grid(grid: &[Vec<char>], rot: Rotation) -> Vec<Vec<char>> {
+    match rot {
+        Rotation::Degree0 => grid.to_vec(),
+
+        Rotation::Degree90 => {
+            // new[y][x] = old[h-1-x][y]
+            let h = grid.len();
+            let w = grid[0].len();
+            (0..w)
+                .map(|y| (0..h).map(|x| grid[h - 1 - x][y]).collect())
+                .collect()
+        }
+
+        Rotation::Degree180 => {
+            let h = grid.len();
+            let w = grid[0].len();
+            (0..h)
+                .map(|y| (0..w).map(|x| grid[h - 1 - y][w - 1 - x]).collect())
+                .collect()
+        }
+
+        Rotation::Degree270 => {
+            // new[y][x] = old[x][w-1-y]
+            let h = grid.len();
+            let w = grid[0].len();
+            (0..w)
+                .map(|y| (0..h).map(|x| grid[x][w - 1 - y]).collect())
+                .collect()
+        }
+    }
+}
+
+fn flip_grid(grid: &[Vec<char>], flip: Flip) -> Vec<Vec<char>> {
+    match flip {
+        Flip::None => grid.to_vec(),
+
+        Flip::Horizontal => {
+            // mirror left-right: reverse each row
+            grid.iter()
+                .map(|row| row.iter().copied().rev().collect())
+                .collect()
+        }
+
+        Flip::Vertical => {
+            // mirror top-bottom: reverse row order
+            grid.iter().cloned().rev().collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.dat");
+
+    fn shape(rows: &[&str]) -> Vec<Vec<char>> {
+        rows.iter().map(|row| row.chars().collect()).collect()
+    }
+
+    #[test]
+    fn example_part1() {
+        let problem = parse(EXAMPLE).unwrap();
+        assert_eq!(solve1(&problem), 2);
+    }
+
+    #[test]
+    fn rotations() {
+        let piece = shape(&["##.",
+                            "#.."]);
+
+        assert_eq!(rotate_grid(&piece, Rotation::Degree0), piece);
+        assert_eq!(rotate_grid(&piece, Rotation::Degree90), shape(&["##",
+                                                                    ".#",
+                                                                    ".."]));
+        assert_eq!(rotate_grid(&piece, Rotation::Degree180), shape(&["..#",
+                                                                     ".##"]));
+        assert_eq!(rotate_grid(&piece, Rotation::Degree270), shape(&["..",
+                                                                     "#.",
+                                                                     "##"]));
+    }
+
+    #[test]
+    fn four_quarter_turns_are_the_identity() {
+        let piece = shape(&["###",
+                            "#..",
+                            "##."]);
+        let mut rotated = piece.clone();
+        for _ in 0..4 {
+            rotated = rotate_grid(&rotated, Rotation::Degree90);
+        }
+        assert_eq!(rotated, piece);
+    }
+
+    #[test]
+    fn flips() {
+        let piece = shape(&["##.",
+                            "#.."]);
+
+        assert_eq!(flip_grid(&piece, Flip::None), piece);
+        assert_eq!(flip_grid(&piece, Flip::Horizontal), shape(&[".##",
+                                                                "..#"]));
+        assert_eq!(flip_grid(&piece, Flip::Vertical), shape(&["#..",
+                                                              "##."]));
+    }
+}
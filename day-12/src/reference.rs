@@ -0,0 +1,75 @@
+//! Part one by plain backtracking over every cell of the region, for regions a few cells across.
+
+use crate::{Problem, Puzzle};
+
+type Shape = Vec<Vec<char>>;
+
+/// Tries every present in every orientation at every position, without pruning or caching.
+pub fn solve1(problem: &Problem) -> usize {
+    let orientations: Vec<Vec<Shape>> = problem.pieces.iter().map(|piece| orientations(&piece.shape)).collect();
+    problem.puzzles.iter().filter(|puzzle| fits(&orientations, puzzle)).count()
+}
+
+fn fits(orientations: &[Vec<Shape>], puzzle: &Puzzle) -> bool {
+    let presents: Vec<usize> = puzzle.amount_of_pieces_to_place
+                                     .iter()
+                                     .enumerate()
+                                     .flat_map(|(index, &amount)| std::iter::repeat_n(index, amount))
+                                     .collect();
+    let mut region = vec![vec![false; puzzle.grid_x_size]; puzzle.grid_y_size];
+    place(orientations, &presents, &mut region)
+}
+
+fn place(orientations: &[Vec<Shape>], presents: &[usize], region: &mut [Vec<bool>]) -> bool {
+    let Some((&present, rest)) = presents.split_first() else {
+        return true;
+    };
+
+    let width = region.first().map_or(0, Vec::len);
+    for shape in &orientations[present] {
+        let cells: Vec<(usize, usize)> = shape.iter().enumerate().flat_map(|(y, row)| {
+            row.iter().enumerate().filter(|(_, &c)| c == '#').map(move |(x, _)| (x, y))
+        }).collect();
+
+        for top in 0..region.len() {
+            for left in 0..width {
+                let free = cells.iter().all(|&(x, y)| {
+                    region.get(top + y).and_then(|row| row.get(left + x)) == Some(&false)
+                });
+                if !free {
+                    continue;
+                }
+
+                cells.iter().for_each(|&(x, y)| region[top + y][left + x] = true);
+                if place(orientations, rest, region) {
+                    return true;
+                }
+                cells.iter().for_each(|&(x, y)| region[top + y][left + x] = false);
+            }
+        }
+    }
+
+    false
+}
+
+/// The distinct shapes reached by turning the shape and by mirroring it.
+fn orientations(shape: &Shape) -> Vec<Shape> {
+    let mut result: Vec<Shape> = Vec::new();
+    let mut current = shape.clone();
+    for _ in 0..4 {
+        let mirrored: Shape = current.iter().map(|row| row.iter().rev().copied().collect()).collect();
+        for candidate in [current.clone(), mirrored] {
+            if !result.contains(&candidate) {
+                result.push(candidate);
+            }
+        }
+        current = turn(&current);
+    }
+    result
+}
+
+/// Turns the shape a quarter clockwise.
+fn turn(shape: &Shape) -> Shape {
+    let height = shape.len();
+    (0..shape[0].len()).map(|x| (0..height).map(|y| shape[height - 1 - y][x]).collect()).collect()
+}
@@ -0,0 +1,35 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// Up to three 3x3 presents, each with its center filled, and a few small regions.
+fn problems() -> impl Strategy<Value = String> {
+    (1usize..=3).prop_flat_map(|pieces| {
+        (vec(vec(any::<bool>(), 9), pieces),
+         vec((3usize..=6, 3usize..=6, vec(0usize..=2, pieces)), 1..=3))
+    }).prop_map(|(pieces, regions)| {
+        let mut text = String::new();
+        for (index, cells) in pieces.iter().enumerate() {
+            text += &format!("{}:\n", index);
+            for y in 0..3 {
+                text.extend((0..3).map(|x| if cells[y * 3 + x] || (x, y) == (1, 1) { '#' } else { '.' }));
+                text.push('\n');
+            }
+            text.push('\n');
+        }
+        for (width, height, counts) in regions {
+            let counts: Vec<String> = counts.iter().map(usize::to_string).collect();
+            text += &format!("{}x{}: {}\n", width, height, counts.join(" "));
+        }
+        text
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(4000))]
+
+    #[test]
+    fn part1_matches_brute_force(text in problems()) {
+        let problem = day_12::parse(&text).unwrap();
+        prop_assert_eq!(day_12::solve1(&problem), day_12::reference::solve1(&problem), "input:\n{}", text);
+    }
+}
@@ -0,0 +1,20 @@
+use std::env;
+use std::path::Path;
+
+/// Generates the C header for the functions in `src/lib.rs` into `OUT_DIR`. The copy in
+/// `include/aoc.h` is for C programs, a test checks that it matches.
+fn main() {
+    let root = env::var("CARGO_MANIFEST_DIR").expect("cargo sets the manifest directory");
+    let root = Path::new(&root);
+    let out = env::var("OUT_DIR").expect("cargo sets the output directory");
+    let config = cbindgen::Config::from_file(root.join("cbindgen.toml")).expect("cbindgen.toml is valid");
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(root.join("src/lib.rs"))
+        .generate()
+        .expect("the header generates")
+        .write_to_file(Path::new(&out).join("aoc.h"));
+
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+}
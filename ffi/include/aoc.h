@@ -0,0 +1,70 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated by cbindgen from src/lib.rs, do not edit. The build script writes it to OUT_DIR. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The answer is in `out`.
+ */
+#define AOC_OK 0
+
+/**
+ * The part has no answer, e.g. the second part of the last day. `out` holds `-`.
+ */
+#define AOC_UNSOLVED 1
+
+/**
+ * There is no solution for the day.
+ */
+#define AOC_UNKNOWN_DAY 2
+
+/**
+ * The part is neither 1 nor 2.
+ */
+#define AOC_UNKNOWN_PART 3
+
+/**
+ * `input` or `out` is null.
+ */
+#define AOC_NULL_POINTER 4
+
+/**
+ * The input is not UTF-8.
+ */
+#define AOC_INVALID_UTF8 5
+
+/**
+ * The input is not a puzzle input of the day. `out` holds where and why, e.g.
+ * `2:1: expected 'L' or 'R', found 'X'`.
+ */
+#define AOC_PARSE_ERROR 6
+
+/**
+ * The answer or message does not fit in `len` bytes, `out` holds as much of it as fits.
+ */
+#define AOC_BUFFER_TOO_SMALL 7
+
+/**
+ * The solver panicked, `out` holds its message.
+ */
+#define AOC_PANIC 8
+
+/**
+ * Answers `part` of `day` for the NUL-terminated puzzle input `input`, writing the answer as
+ * NUL-terminated text to the `len` bytes at `out`. Returns `AOC_OK` or one of the other codes,
+ * each of which tells what `out` holds instead.
+ *
+ * # Safety
+ *
+ * `input` must be null or point to a NUL-terminated string, and `out` null or point to `len`
+ * writable bytes.
+ */
+int32_t aoc_solve(uint32_t day, uint32_t part, const char *input, char *out, size_t len);
+
+#endif  /* AOC_H */
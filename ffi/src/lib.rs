@@ -0,0 +1,126 @@
+//! The solvers behind a C ABI, for test harnesses that are not written in Rust. The build script
+//! generates the header, `include/aoc.h` keeps a copy of it.
+
+use std::ffi::{c_char, CStr};
+use std::panic::{self, AssertUnwindSafe};
+
+use common::{Answer, ParseError, Solution};
+use solutions::with_day;
+
+/// The answer is in `out`.
+pub const AOC_OK: i32 = 0;
+/// The part has no answer, e.g. the second part of the last day. `out` holds `-`.
+pub const AOC_UNSOLVED: i32 = 1;
+/// There is no solution for the day.
+pub const AOC_UNKNOWN_DAY: i32 = 2;
+/// The part is neither 1 nor 2.
+pub const AOC_UNKNOWN_PART: i32 = 3;
+/// `input` or `out` is null.
+pub const AOC_NULL_POINTER: i32 = 4;
+/// The input is not UTF-8.
+pub const AOC_INVALID_UTF8: i32 = 5;
+/// The input is not a puzzle input of the day. `out` holds where and why, e.g.
+/// `2:1: expected 'L' or 'R', found 'X'`.
+pub const AOC_PARSE_ERROR: i32 = 6;
+/// The answer or message does not fit in `len` bytes, `out` holds as much of it as fits.
+pub const AOC_BUFFER_TOO_SMALL: i32 = 7;
+/// The solver panicked, `out` holds its message.
+pub const AOC_PANIC: i32 = 8;
+
+/// Answers `part` of `day` for the NUL-terminated puzzle input `input`, writing the answer as
+/// NUL-terminated text to the `len` bytes at `out`. Returns `AOC_OK` or one of the other codes,
+/// each of which tells what `out` holds instead.
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string, and `out` null or point to `len`
+/// writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(day: u32, part: u32, input: *const c_char, out: *mut c_char, len: usize) -> i32 {
+    if input.is_null() || out.is_null() {
+        return AOC_NULL_POINTER;
+    }
+    let (status, text) = match CStr::from_ptr(input).to_str() {
+        Err(_) => (AOC_INVALID_UTF8, String::new()),
+        Ok(text) => match panic::catch_unwind(AssertUnwindSafe(|| solve(day, part, text))) {
+            Ok(Ok(Answer::Unsolved)) => (AOC_UNSOLVED, Answer::Unsolved.to_string()),
+            Ok(Ok(answer)) => (AOC_OK, answer.to_string()),
+            Ok(Err(Error::UnknownDay)) => (AOC_UNKNOWN_DAY, String::new()),
+            Ok(Err(Error::UnknownPart)) => (AOC_UNKNOWN_PART, String::new()),
+            Ok(Err(Error::Parse(error))) => (AOC_PARSE_ERROR, error.to_string()),
+            Err(payload) => (AOC_PANIC, solutions::panic_message(payload)),
+        },
+    };
+
+    // Only now that the input is done with, in case the caller reuses its buffer for the answer
+    let out = if len == 0 { &mut [][..] } else { std::slice::from_raw_parts_mut(out.cast::<u8>(), len) };
+    if write(out, &text) {
+        status
+    } else {
+        AOC_BUFFER_TOO_SMALL
+    }
+}
+
+enum Error {
+    UnknownDay,
+    UnknownPart,
+    Parse(ParseError),
+}
+
+fn solve(day: u32, part: u32, text: &str) -> Result<Answer, Error> {
+    with_day!(day, S => {
+        let part = u8::try_from(part).ok().filter(|part| matches!(part, 1 | 2)).ok_or(Error::UnknownPart)?;
+        let input = S::parse(text).map_err(Error::Parse)?;
+        Ok(S::solve(&input, part))
+    }, _ => Err(Error::UnknownDay))
+}
+
+/// Copies `text` and a NUL to `out`, or as many whole characters of `text` as fit with the NUL.
+/// Returns whether all of it fit.
+fn write(out: &mut [u8], text: &str) -> bool {
+    let Some(room) = out.len().checked_sub(1) else { return false };
+    let length = text.floor_char_boundary(room);
+    out[..length].copy_from_slice(&text.as_bytes()[..length]);
+    out[length] = 0;
+    length == text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::ptr;
+
+    use super::*;
+
+    fn solve(day: u32, part: u32, input: &str, len: usize) -> (i32, String) {
+        let input = CString::new(input).unwrap();
+        let mut out = vec![0x55u8; len];
+        let status = unsafe { aoc_solve(day, part, input.as_ptr(), out.as_mut_ptr().cast(), len) };
+        let end = out.iter().position(|&byte| byte == 0).unwrap_or(out.len());
+        (status, String::from_utf8_lossy(&out[..end]).into_owned())
+    }
+
+    #[test]
+    fn answers_in_the_buffer() {
+        assert_eq!(solve(1, 2, "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n", 64), (AOC_OK, "6".to_string()));
+        assert_eq!(solve(1, 1, "L68\nX12\n", 64), (AOC_PARSE_ERROR, "2:1: expected 'L' or 'R', found 'X'".to_string()));
+        assert_eq!(solve(13, 1, "", 64), (AOC_UNKNOWN_DAY, String::new()));
+        assert_eq!(solve(1, 3, "", 64), (AOC_UNKNOWN_PART, String::new()));
+    }
+
+    #[test]
+    fn truncates_what_does_not_fit() {
+        assert_eq!(solve(1, 1, "L68\nX12\n", 5), (AOC_BUFFER_TOO_SMALL, "2:1:".to_string()));
+        assert_eq!(solve(1, 1, "L50\n", 1), (AOC_BUFFER_TOO_SMALL, String::new()));
+
+        // A parse error quotes the character it found, which must not be cut in half
+        let (status, message) = solve(1, 1, "é\n", 35);
+        assert_eq!((status, message.as_str()), (AOC_BUFFER_TOO_SMALL, "1:1: expected 'L' or 'R', found '"));
+
+        let mut out = [0x55u8; 1];
+        let input = CString::new("L50\n").unwrap();
+        assert_eq!(unsafe { aoc_solve(1, 1, input.as_ptr(), out.as_mut_ptr().cast(), 0) }, AOC_BUFFER_TOO_SMALL);
+        assert_eq!(out, [0x55], "nothing is written to an empty buffer");
+        assert_eq!(unsafe { aoc_solve(1, 1, ptr::null(), out.as_mut_ptr().cast(), 1) }, AOC_NULL_POINTER);
+    }
+}
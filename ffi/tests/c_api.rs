@@ -0,0 +1,58 @@
+use std::path::Path;
+use std::process::Command;
+use std::{env, fs};
+
+/// The build script generates the header anew on every change, the copy for C programs must keep up.
+#[test]
+fn header_is_up_to_date() {
+    let generated = fs::read_to_string(Path::new(env!("OUT_DIR")).join("aoc.h")).unwrap();
+    let committed = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc.h")).unwrap();
+    assert!(committed == generated, "include/aoc.h is out of date, the build generated:\n{}", generated);
+}
+
+/// Builds `solve.c` against the library with the system C compiler, runs it on the examples and
+/// compares its answers with the answers file.
+#[test]
+fn c_program_answers_every_example() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let root = manifest.join("..");
+    // The test runs from the directory cargo builds the library into
+    let exe = env::current_exe().unwrap();
+    let library = exe.parent().unwrap();
+    let program = env::temp_dir().join(format!("aoc-ffi-solve-{}", std::process::id()));
+
+    let compiled = Command::new("cc")
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(manifest.join("include"))
+        .arg(manifest.join("tests/solve.c"))
+        .arg("-L")
+        .arg(library)
+        .arg("-laoc_ffi")
+        .arg(format!("-Wl,-rpath,{}", library.display()))
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("the test program needs a C compiler, `cc`");
+    assert!(compiled.success(), "solve.c does not compile");
+
+    // Cargo's library path lists `target/debug` first, whose copy of the library only `cargo build`
+    // refreshes, and it would win over the rpath
+    let output = Command::new(&program).arg(&root).env_remove("LD_LIBRARY_PATH").output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}{}", stdout, String::from_utf8_lossy(&output.stderr));
+
+    let answers: Vec<(u8, u8, &str)> = stdout.lines().map(|line| {
+        let mut fields = line.splitn(3, ' ');
+        let mut number = || fields.next().unwrap().parse().unwrap();
+        (number(), number(), fields.next().unwrap())
+    }).collect();
+    let cases = common::answers::load(common::answers::FILENAME).unwrap();
+    let examples: Vec<_> = cases.iter().filter(|case| case.input == "test.dat").collect();
+    assert!(!examples.is_empty());
+    for case in examples {
+        let answer = answers.iter().find(|&&(day, part, _)| day == case.day && part == case.part);
+        assert_eq!(answer.map(|&(_, _, answer)| answer.to_string()),
+                   Some(case.expected.to_string()),
+                   "day {} part {}", case.day, case.part);
+    }
+}
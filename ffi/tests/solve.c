@@ -0,0 +1,87 @@
+/*
+ * Exercises the C API: answers both parts of every day's example, printing `day part answer`
+ * lines, and checks the error codes. Takes the workspace root as its argument, tests/c_api.rs
+ * builds and runs it with `cargo test -p aoc-ffi`. By hand:
+ *
+ *     cc -std=c99 -Wall -Wextra -Werror -I ffi/include ffi/tests/solve.c -L target/debug -laoc_ffi -o solve
+ *     LD_LIBRARY_PATH=target/debug ./solve .
+ */
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "aoc.h"
+
+/* Room for any answer the days give, and for their error messages. */
+#define OUT_LEN 256
+
+static int failures = 0;
+
+static void check(int passed, const char *what) {
+    if (!passed) {
+        fprintf(stderr, "FAIL: %s\n", what);
+        failures++;
+    }
+}
+
+/* The whole file as a NUL-terminated string, NULL when it cannot be read. */
+static char *read_file(const char *path) {
+    FILE *file = fopen(path, "rb");
+    if (file == NULL) {
+        return NULL;
+    }
+    size_t capacity = 4096, length = 0, read;
+    char *text = malloc(capacity);
+    while (text != NULL && (read = fread(text + length, 1, capacity - length - 1, file)) > 0) {
+        length += read;
+        if (capacity - length == 1) {
+            char *grown = realloc(text, capacity *= 2);
+            if (grown == NULL) {
+                free(text);
+            }
+            text = grown;
+        }
+    }
+    fclose(file);
+    if (text != NULL) {
+        text[length] = '\0';
+    }
+    return text;
+}
+
+int main(int argc, char **argv) {
+    const char *root = argc > 1 ? argv[1] : ".";
+    char path[4096];
+    char out[OUT_LEN];
+
+    /* Days without a solution have no directory, so only the registered ones are answered */
+    for (uint32_t day = 1; day <= 25; day++) {
+        snprintf(path, sizeof path, "%s/day-%02u/input/test.dat", root, (unsigned) day);
+        char *input = read_file(path);
+        if (input == NULL) {
+            continue;
+        }
+        for (uint32_t part = 1; part <= 2; part++) {
+            int32_t status = aoc_solve(day, part, input, out, sizeof out);
+            if (status == AOC_OK || status == AOC_UNSOLVED) {
+                printf("%u %u %s\n", (unsigned) day, (unsigned) part, out);
+            } else {
+                fprintf(stderr, "FAIL: day %u part %u: status %d, %s\n", (unsigned) day, (unsigned) part, (int) status, out);
+                failures++;
+            }
+        }
+        free(input);
+    }
+
+    check(aoc_solve(1, 1, "L68\nX12\n", out, sizeof out) == AOC_PARSE_ERROR, "a parse error");
+    check(strcmp(out, "2:1: expected 'L' or 'R', found 'X'") == 0, "the parse error names line and column");
+    check(aoc_solve(26, 1, "", out, sizeof out) == AOC_UNKNOWN_DAY, "an unknown day");
+    check(aoc_solve(1, 3, "", out, sizeof out) == AOC_UNKNOWN_PART, "an unknown part");
+    check(aoc_solve(1, 1, NULL, out, sizeof out) == AOC_NULL_POINTER, "a null input");
+    check(aoc_solve(1, 1, "L68\n", NULL, 0) == AOC_NULL_POINTER, "a null buffer");
+    check(aoc_solve(1, 1, "L\xff\n", out, sizeof out) == AOC_INVALID_UTF8, "an input that is not UTF-8");
+    check(aoc_solve(1, 1, "L68\nX12\n", out, 5) == AOC_BUFFER_TOO_SMALL, "a buffer too small");
+    check(strcmp(out, "2:1:") == 0, "a message cut to the buffer");
+
+    return failures == 0 ? EXIT_SUCCESS : EXIT_FAILURE;
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32
+}
+
+const NEIGHBOURS_4: [Point; 4] = [
+    Point { x: -1, y: 0 },
+    Point { x: 1, y: 0 },
+    Point { x: 0, y: -1 },
+    Point { x: 0, y: 1 },
+];
+
+const NEIGHBOURS_8: [Point; 8] = [
+    Point { x: -1, y: 0 },
+    Point { x: 1, y: 0 },
+    Point { x: 0, y: -1 },
+    Point { x: 0, y: 1 },
+
+    Point { x: -1, y: -1 },
+    Point { x: -1, y: 1 },
+    Point { x: 1, y: -1 },
+    Point { x: 1, y: 1 },
+];
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn add(&self, other: &Point) -> Self {
+        Point { x: self.x + other.x, y: self.y + other.y }
+    }
+
+    /// The points directly left, right, above and below, without bounds checks.
+    pub fn neighbours4(&self) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_4.iter().map(move |d| self.add(d))
+    }
+
+    /// `neighbours4` plus the four diagonals, without bounds checks.
+    pub fn neighbours8(&self) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_8.iter().map(move |d| self.add(d))
+    }
+}
+
+/// A rectangular grid stored row by row, addressed with `Point { x: column, y: row }`.
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, panics when the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(rows.iter().all(|row| row.len() == width), "grid rows differ in length");
+
+        Grid {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height as i32
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width as i32
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        point.x >= 0
+            && point.x < self.width()
+            && point.y >= 0
+            && point.y < self.height()
+    }
+
+    fn index(&self, point: &Point) -> Option<usize> {
+        if self.contains(point) {
+            Some(point.y as usize * self.width + point.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        self.index(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        self.index(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Overwrites the value at `point`, panics when it lies outside the grid.
+    pub fn set(&mut self, point: &Point, value: T) {
+        let index = self.index(point)
+                        .unwrap_or_else(|| panic!("{:?} lies outside the grid", point));
+        self.cells[index] = value;
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, an empty grid simply has no rows
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// All points, left to right and then top to bottom.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let w = self.width();
+        let h = self.height();
+
+        (0..h).flat_map(move |y| {
+            (0..w).map(move |x| Point::new(x, y))
+        })
+    }
+
+    /// All points, top to bottom and then left to right.
+    pub fn points_column_major(&self) -> impl Iterator<Item = Point> {
+        let w = self.width();
+        let h = self.height();
+
+        (0..w).flat_map(move |x| {
+            (0..h).map(move |y| Point::new(x, y))
+        })
+    }
+
+    pub fn neighbours4<'a>(&'a self, point: &'a Point) -> impl Iterator<Item = Point> + 'a {
+        point.neighbours4().filter(|n| self.contains(n))
+    }
+
+    pub fn neighbours8<'a>(&'a self, point: &'a Point) -> impl Iterator<Item = Point> + 'a {
+        point.neighbours8().filter(|n| self.contains(n))
+    }
+}
+
+impl Grid<char> {
+    pub fn from_lines<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Grid::from_rows(lines.into_iter().map(|line| line.as_ref().chars().collect()).collect())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coordinates(points: impl Iterator<Item = Point>) -> Vec<(i32, i32)> {
+        points.map(|p| (p.x, p.y)).collect()
+    }
+
+    fn sorted(points: impl Iterator<Item = Point>) -> Vec<(i32, i32)> {
+        let mut points = coordinates(points);
+        points.sort();
+        points
+    }
+
+    #[test]
+    fn reads_and_writes_only_inside_the_grid() {
+        let mut grid = Grid::new(3, 2, 0);
+        grid.set(&Point::new(2, 1), 7);
+        *grid.get_mut(&Point::new(0, 1)).unwrap() = 5;
+
+        assert_eq!(grid.get(&Point::new(2, 1)), Some(&7));
+        assert_eq!(grid.get(&Point::new(0, 1)), Some(&5));
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid.get(&Point::new(0, 2)), None);
+        assert_eq!(grid.get(&Point::new(-1, 0)), None);
+        assert_eq!(grid.get_mut(&Point::new(0, -1)), None);
+        assert_eq!(grid.values().sum::<i32>(), 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "lies outside the grid")]
+    fn refuses_to_set_outside_the_grid() {
+        Grid::new(3, 2, 0).set(&Point::new(3, 1), 1);
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = Grid::new(3, 3, '.');
+
+        assert_eq!(sorted(grid.neighbours4(&Point::new(0, 0))), vec![(0, 1), (1, 0)]);
+        assert_eq!(sorted(grid.neighbours8(&Point::new(0, 0))), vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(sorted(grid.neighbours4(&Point::new(2, 1))), vec![(1, 1), (2, 0), (2, 2)]);
+        assert_eq!(sorted(grid.neighbours8(&Point::new(2, 1))), vec![(1, 0), (1, 1), (1, 2), (2, 0), (2, 2)]);
+        assert_eq!(grid.neighbours4(&Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(&Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn builds_from_lines() {
+        let grid = Grid::from_lines(["#..", ".#."]);
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(&Point::new(1, 1)), Some(&'#'));
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&['#', '.', '.'][..], &['.', '#', '.'][..]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid rows differ in length")]
+    fn rejects_rows_of_different_lengths() {
+        Grid::from_rows(vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn displays_row_by_row() {
+        let grid = Grid::from_lines(["#..", ".#."]);
+        assert_eq!(grid.to_string(), "#..\n.#.\n");
+        assert_eq!(Grid::<char>::from_lines(Vec::<&str>::new()).to_string(), "");
+    }
+
+    #[test]
+    fn visits_points_by_row_or_by_column() {
+        let grid = Grid::new(2, 3, 0);
+
+        assert_eq!(coordinates(grid.points()), vec![(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)]);
+        assert_eq!(coordinates(grid.points_column_major()), vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    }
+}
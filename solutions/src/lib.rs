@@ -0,0 +1,51 @@
+//! Every day's solution, for the runner and the C library that pick a day at run time.
+
+pub use day_01;
+pub use day_02;
+pub use day_03;
+pub use day_04;
+pub use day_05;
+pub use day_06;
+pub use day_07;
+pub use day_08;
+pub use day_09;
+pub use day_10;
+pub use day_11;
+pub use day_12;
+
+/// Runs `$body` with `$solution` naming the `Solution` of `$day`. Days without a solution evaluate
+/// `$unknown`, or panic when it is left out because the day was checked against `DAYS` already.
+#[macro_export]
+macro_rules! with_day {
+    ($day:expr, $solution:ident => $body:expr) => {
+        $crate::with_day!($day, $solution => $body, _ => unreachable!("day {} has no solution", $day))
+    };
+    ($day:expr, $solution:ident => $body:expr, _ => $unknown:expr) => {
+        match $day {
+            1 => { type $solution = $crate::day_01::Day01; $body }
+            2 => { type $solution = $crate::day_02::Day02; $body }
+            3 => { type $solution = $crate::day_03::Day03; $body }
+            4 => { type $solution = $crate::day_04::Day04; $body }
+            5 => { type $solution = $crate::day_05::Day05; $body }
+            6 => { type $solution = $crate::day_06::Day06; $body }
+            7 => { type $solution = $crate::day_07::Day07; $body }
+            8 => { type $solution = $crate::day_08::Day08; $body }
+            9 => { type $solution = $crate::day_09::Day09; $body }
+            10 => { type $solution = $crate::day_10::Day10; $body }
+            11 => { type $solution = $crate::day_11::Day11; $body }
+            12 => { type $solution = $crate::day_12::Day12; $body }
+            _ => $unknown,
+        }
+    };
+}
+
+/// The days that have a solution, `aoc new` adds to them.
+pub const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+/// The message a panic was raised with, as far as it is text.
+pub fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or("panicked".to_string(), |message| message.to_string()),
+    }
+}